@@ -0,0 +1,21 @@
+//! Day {{day}} of {{year}}: {{title}}
+
+use advent_of_code::Error;
+
+pub fn part_one(_input: &str) -> Result<{{answer_type}}, Error> {
+    Err(Error::logic("Not solved"))
+}
+
+pub fn part_two(_input: &str) -> Result<{{answer_type}}, Error> {
+    Err(Error::logic("Not solved"))
+}
+
+advent_of_code::solution!({{year}}, {{day}}, part_one, part_two);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // passes until the examples have answers, drop `skip_missing` once they do.
+    advent_of_code::example_tests!({{year}}, {{day}}, part_one, part_two, skip_missing);
+}
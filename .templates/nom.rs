@@ -0,0 +1,62 @@
+//! Day {{day}} of {{year}}: {{title}}
+
+use advent_of_code::Error;
+use std::str::FromStr;
+
+pub fn part_one(input: &str) -> Result<{{answer_type}}, Error> {
+    let Puzzle { lines: _lines } = input.parse()?;
+    Err(Error::logic("Not solved"))
+}
+
+pub fn part_two(input: &str) -> Result<{{answer_type}}, Error> {
+    let Puzzle { lines: _lines } = input.parse()?;
+    Err(Error::logic("Not solved"))
+}
+
+advent_of_code::solution!({{year}}, {{day}}, part_one, part_two);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    lines: Vec<String>,
+}
+
+impl FromStr for Puzzle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        advent_of_code::helpers::parse::parse_all(parser::parse_puzzle, s)
+    }
+}
+
+mod parser {
+    use super::Puzzle;
+    use advent_of_code::helpers::parse::{lines_of, Res};
+    use nom::character::complete::not_line_ending;
+    use nom::combinator::map;
+
+    pub fn parse_line(input: &str) -> Res<'_, String> {
+        map(not_line_ending, str::to_string)(input)
+    }
+
+    pub fn parse_puzzle(input: &str) -> Res<'_, Puzzle> {
+        map(lines_of(parse_line), |lines| Puzzle {
+            lines,
+        })(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for example in advent_of_code::examples::load({{year}}, {{day}}).unwrap() {
+            let parsed = example.input.parse::<Puzzle>();
+            assert!(parsed.is_ok(), "example {}: {:?}", example.name, parsed);
+        }
+    }
+
+    // passes until the examples have answers, drop `skip_missing` once they do.
+    advent_of_code::example_tests!({{year}}, {{day}}, part_one, part_two, skip_missing);
+}
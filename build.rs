@@ -0,0 +1,78 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Collects the days that have a `src/yYYYY/NN.rs` module, sorted by year and day.
+fn find_days(src_dir: &Path) -> Vec<(u16, u8, PathBuf)> {
+    let mut days = vec![];
+
+    for entry in fs::read_dir(src_dir).expect("could not read src") {
+        let year_dir = entry.expect("could not read src").path();
+        let year = year_dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix('y'))
+            .filter(|year| year.len() == 4)
+            .and_then(|year| year.parse::<u16>().ok());
+        let year = match year {
+            Some(year) if year_dir.is_dir() => year,
+            _ => continue,
+        };
+
+        for entry in fs::read_dir(&year_dir).expect("could not read year folder") {
+            let path = entry.expect("could not read year folder").path();
+            let day = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .filter(|stem| stem.len() == 2 && path.extension().is_some_and(|ext| ext == "rs"))
+                .and_then(|stem| stem.parse::<u8>().ok());
+            if let Some(day) = day {
+                days.push((year, day, path));
+            }
+        }
+    }
+
+    days.sort();
+    days
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let src_dir = manifest_dir.join("src");
+    let days = find_days(&src_dir);
+
+    // scans the whole folder, so new year folders and days are picked up without a clean build.
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    // every day is included as a module of the runner, which registers its `SOLUTION` to call it in-process.
+    // days may not print to stdout, which carries nothing but the records with `--json`, `eprintln!` is fine.
+    let mut code = String::new();
+    for (year, day, path) in &days {
+        writeln!(
+            code,
+            "#[path = {:?}]\n#[allow(dead_code)]\n#[deny(clippy::print_stdout)]\nmod y{}_day{:02};",
+            path.display().to_string(),
+            year,
+            day
+        )
+        .unwrap();
+    }
+
+    code.push_str("\nfn register_days(registry: &mut advent_of_code::registry::Registry) {\n");
+    for (year, day, _) in &days {
+        writeln!(
+            code,
+            "    registry.register(y{}_day{:02}::SOLUTION);",
+            year, day
+        )
+        .unwrap();
+    }
+    code.push_str("}\n");
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out_path, code).expect("could not write days.rs");
+}
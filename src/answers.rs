@@ -0,0 +1,215 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::registry::Outcome;
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::{fs, io, process};
+
+/// Confirmed answers of a day, stored in `src/answers/<year>/NN.txt` as:
+///
+/// ```text
+/// --- part 1 ---
+/// 69177
+/// --- part 2 ---
+/// 207456
+/// ```
+///
+/// Everything between two headers is the answer, so multi-line answers are allowed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    part_one: Option<String>,
+    part_two: Option<String>,
+}
+
+/// How an outcome compares to the confirmed answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong { .. } => "wrong",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Verdict::Wrong { .. })
+    }
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Answers {
+        let mut answers = Answers::default();
+        let mut current: Option<(u8, Vec<&str>)> = None;
+
+        for line in content.lines().chain(std::iter::once("--- end ---")) {
+            let header = line
+                .strip_prefix("--- ")
+                .and_then(|rest| rest.strip_suffix(" ---"));
+
+            match header {
+                Some(header) => {
+                    if let Some((part, lines)) = current.take() {
+                        answers.set(part, lines.join("\n").trim_end().to_string());
+                    }
+                    current = header
+                        .strip_prefix("part ")
+                        .and_then(|part| part.trim().parse::<u8>().ok())
+                        .map(|part| (part, vec![]));
+                }
+                None => {
+                    if let Some((_, lines)) = current.as_mut() {
+                        lines.push(line);
+                    }
+                }
+            }
+        }
+
+        answers
+    }
+
+    pub fn path(year: u16, day: u8) -> PathBuf {
+        crate::data_dir("answers", year).join(format!("{:02}.txt", day))
+    }
+
+    /// Reads `src/answers/<year>/NN.txt`, a missing file means no confirmed answers yet.
+    pub fn load(year: u16, day: u8) -> Answers {
+        fs::read_to_string(Answers::path(year, day))
+            .map(|content| Answers::parse(&content))
+            .unwrap_or_default()
+    }
+
+    /// Adds `answer` as the confirmed answer of `part` to `src/answers/<year>/NN.txt`.
+    pub fn record(year: u16, day: u8, part: u8, answer: &str) -> io::Result<()> {
+        let mut answers = Answers::load(year, day);
+        answers.set(part, answer.trim_end().to_string());
+
+        let path = Answers::path(year, day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, answers.render())
+    }
+
+    pub fn render(&self) -> String {
+        let mut content = String::new();
+        for part in 1..=2 {
+            if let Some(answer) = self.get(part) {
+                writeln!(content, "--- part {} ---\n{}", part, answer).unwrap();
+            }
+        }
+        content
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn set(&mut self, part: u8, answer: String) {
+        if answer.is_empty() {
+            return;
+        }
+        match part {
+            1 => self.part_one = Some(answer),
+            2 => self.part_two = Some(answer),
+            _ => {}
+        }
+    }
+
+    /// A known answer that is not reproduced, because it differs or the part failed, is wrong.
+    /// Without an input there is nothing to compare, so it stays unknown.
+    pub fn verify(&self, part: u8, outcome: &Outcome) -> Verdict {
+        match (self.get(part), outcome) {
+            (None, _) | (_, Outcome::MissingInput { .. }) => Verdict::Unknown,
+            (Some(expected), Outcome::Solved { answer, .. }) if answer.trim_end() == expected => {
+                Verdict::Correct
+            }
+            (Some(expected), _) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+/// Exits with status 1 if any part no longer produces its confirmed answer.
+pub fn exit_on_regression(verdicts: &[Verdict]) {
+    if verdicts.iter().any(Verdict::is_regression) {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn solved(answer: &str) -> Outcome {
+        Outcome::Solved {
+            answer: answer.to_string(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("--- part 1 ---\n69177\n--- part 2 ---\n207456\n");
+        assert_eq!(answers.get(1), Some("69177"));
+        assert_eq!(answers.get(2), Some("207456"));
+
+        let multiline = Answers::parse("--- part 2 ---\n##..\n.##.\n\n");
+        assert_eq!(multiline.get(1), None);
+        assert_eq!(multiline.get(2), Some("##..\n.##."));
+
+        assert_eq!(Answers::parse(""), Answers::default());
+    }
+
+    #[test]
+    fn test_render() {
+        let content = "--- part 1 ---\n69177\n--- part 2 ---\n##..\n.##.\n";
+        assert_eq!(Answers::parse(content).render(), content);
+        assert_eq!(Answers::default().render(), "");
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("--- part 1 ---\n24000\n");
+
+        assert_eq!(answers.verify(1, &solved("24000")), Verdict::Correct);
+        assert_eq!(
+            answers.verify(1, &solved("24001")),
+            Verdict::Wrong {
+                expected: "24000".to_string()
+            }
+        );
+        assert!(answers
+            .verify(
+                1,
+                &Outcome::Panicked {
+                    message: "boom".to_string()
+                }
+            )
+            .is_regression());
+        assert_eq!(answers.verify(2, &solved("1")), Verdict::Unknown);
+        assert_eq!(
+            answers.verify(
+                1,
+                &Outcome::MissingInput {
+                    error: "no input".to_string()
+                }
+            ),
+            Verdict::Unknown
+        );
+    }
+}
@@ -0,0 +1,161 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::registry::Solver;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Settings of `--bench`: untimed warm-up runs followed by timed iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bench {
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+impl Default for Bench {
+    fn default() -> Bench {
+        Bench {
+            warmup: 5,
+            iterations: 50,
+        }
+    }
+}
+
+impl Bench {
+    /// `--bench` enables benchmarking, `--warmup <n>` and `--iterations <n>` tune it.
+    ///
+    /// The tuning flags are consumed even without `--bench`, so they are never mistaken for other arguments.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Bench>, pico_args::Error> {
+        let enabled = args.contains("--bench");
+        let defaults = Bench::default();
+        let bench = Bench {
+            warmup: args
+                .opt_value_from_str("--warmup")?
+                .unwrap_or(defaults.warmup),
+            iterations: args
+                .opt_value_from_str::<_, u32>("--iterations")?
+                .unwrap_or(defaults.iterations)
+                .max(1),
+        };
+        Ok(enabled.then_some(bench))
+    }
+
+    pub fn run(&self, solver: Solver, input: &str) -> Stats {
+        for _ in 0..self.warmup {
+            black_box(solver(black_box(input))).ok();
+        }
+
+        let mut samples: Vec<Duration> = (0..self.iterations)
+            .map(|_| {
+                let timer = Instant::now();
+                black_box(solver(black_box(input))).ok();
+                timer.elapsed()
+            })
+            .collect();
+
+        Stats::from_samples(&mut samples)
+    }
+}
+
+/// Summary of the timed iterations of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Stats {
+        assert!(!samples.is_empty(), "need at least one sample");
+        samples.sort();
+
+        let n = samples.len();
+        let nanos: Vec<f64> = samples.iter().map(|s| s.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / n as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        // nearest-rank percentile.
+        let p95_rank = ((n as f64) * 0.95).ceil() as usize;
+
+        Stats {
+            iterations: n as u32,
+            min: samples[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            p95: samples[p95_rank.max(1) - 1],
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_micros(*v)).collect()
+    }
+
+    #[test]
+    fn test_from_args() {
+        let parse = |args: &[&str]| {
+            let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+            Bench::from_args(&mut args).map_err(|e| e.to_string())
+        };
+
+        assert_eq!(parse(&[]), Ok(None));
+        assert_eq!(parse(&["--warmup", "2"]), Ok(None));
+        assert_eq!(parse(&["--bench"]), Ok(Some(Bench::default())));
+        assert_eq!(
+            parse(&["--bench", "--warmup", "0", "--iterations", "0"]),
+            Ok(Some(Bench {
+                warmup: 0,
+                iterations: 1
+            }))
+        );
+        assert!(parse(&["--bench", "--iterations", "many"]).is_err());
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&mut micros(&[5, 1, 4, 2, 3]));
+
+        assert_eq!(stats.iterations, 5);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        assert_eq!(stats.std_dev, Duration::from_nanos(1414));
+    }
+
+    #[test]
+    fn test_stats_even_samples() {
+        let stats = Stats::from_samples(&mut micros(&[4, 1, 3, 2]));
+
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.p95, Duration::from_micros(4));
+    }
+
+    #[test]
+    fn test_run_counts_iterations() {
+        let bench = Bench {
+            warmup: 2,
+            iterations: 7,
+        };
+        let stats = bench.run(|input| Ok(input.to_string()), "abc");
+
+        assert_eq!(stats.iterations, 7);
+        assert!(stats.min <= stats.median && stats.median <= stats.p95);
+    }
+}
@@ -2,104 +2,170 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
-use std::{fs, process};
+use advent_of_code::cache::{InputCache, Lookup};
+use advent_of_code::client::{self, Client};
+use advent_of_code::{description, examples, select};
+use std::fs;
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: Option<u16>,
+    force: bool,
+    description: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
+    let year_option = args.opt_value_from_fn(["-y", "--year"], select::parse_year)?;
+    let force = args.contains("--force");
+    let description = args.contains("--description");
+    let (year, day) = select::year_and_day(&mut args)?;
     Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        day,
+        year: year.or(year_option),
+        force,
+        description,
     })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
+fn client() -> Client {
+    match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
     }
 }
 
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
-}
+fn download_input(args: &Args, year: u16) {
+    let cache = InputCache::new(advent_of_code::data_dir("inputs", year));
+    let input_path = cache.input_path(args.day);
 
-fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
+    match cache.lookup(year, args.day) {
+        Lookup::Missing => {}
+        _ if args.force => {}
+        Lookup::Cached { .. } => {
+            println!(
+                "🎄 Input was already downloaded to \"{}\", pass `--force` to download it again.",
+                input_path.display()
+            );
+            return;
+        }
+        Lookup::Conflict { reason } => {
+            eprintln!("{}, pass `--force` to overwrite it.", reason);
+            process::exit(1);
+        }
+    }
 
-    let args = match parse_args() {
-        Ok(args) => args,
+    let client = client();
+    println!(
+        "Downloading input of day {} of {} from {}",
+        args.day, year, client.base_url
+    );
+
+    let input = match client.input(year, args.day) {
+        Ok(input) => input,
         Err(e) => {
-            eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("could not download input: {}", e);
+            process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
 
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
+    match cache.store(year, args.day, &input, now) {
+        Ok(_) => {
+            println!("---");
+            println!(
+                "🎄 Successfully wrote input to \"{}\".",
+                input_path.display()
+            );
+        }
+        Err(e) => {
+            eprintln!("could not write input file: {}", e);
+            process::exit(1);
+        }
     }
+}
 
-    let mut cmd_args = vec![];
-
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+fn download_description(day: u8, year: u16) {
+    let module_path = advent_of_code::module_path(year, day);
+    if !module_path.exists() {
+        eprintln!(
+            "\"{}\" does not exist yet, create it with `cargo scaffold {} {}`.",
+            module_path.display(),
+            year,
+            day
+        );
+        process::exit(1);
     }
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
-
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
-
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
+    let page = match client().puzzle(year, day) {
+        Ok(page) => page,
+        Err(e) => {
+            eprintln!("could not download puzzle description: {}", e);
+            process::exit(1);
         }
+    };
+
+    match description::update(&module_path, &page) {
+        Ok(_) => println!(
+            "🎄 Successfully wrote the puzzle description to \"{}\".",
+            module_path.display()
+        ),
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("could not update module file: {}", e);
+            process::exit(1);
         }
     }
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+    store_examples(year, day, &page);
+}
+
+/// Writes the examples of the description to `src/examples/YYYY/NN/`, pre-filling their answers.
+fn store_examples(year: u16, day: u8, page: &str) {
+    let blocks = description::extract_examples(&description::from_html(page));
+    let examples_dir = advent_of_code::data_dir("examples", year);
+    let dir = examples_dir.join(format!("{:02}", day));
+
+    match examples::store_extracted(&dir, &blocks) {
+        Ok(written) => {
+            for path in written {
+                println!("🎄 Wrote example file \"{}\".", path.display());
+            }
+        }
+        Err(e) => eprintln!("could not write example files: {}", e),
+    }
+
+    // the single example file created by `cargo scaffold` gets the first example, too.
+    let example_path = examples_dir.join(format!("{:02}.txt", day));
+    let first_example = blocks.iter().find_map(|block| block.input.as_ref());
+    if let (Ok(""), Some(input)) = (fs::read_to_string(&example_path).as_deref(), first_example) {
+        match fs::write(&example_path, input) {
+            Ok(_) => println!("🎄 Wrote example file \"{}\".", example_path.display()),
+            Err(e) => eprintln!("could not write example file: {}", e),
         }
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
         }
+    };
+
+    let year = args.year.unwrap_or_else(client::current_year);
+
+    download_input(&args, year);
+    if args.description {
+        download_description(args.day, year);
     }
 }
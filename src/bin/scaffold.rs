@@ -2,113 +2,171 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
-};
-
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(_input: &str) -> Result<u32, String> {
-    Err("Not solved".to_string())
-}
-
-pub fn part_two(_input: &str) -> Result<u32, String> {
-    Err("Not solved".to_string()) 
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+use advent_of_code::client;
+use advent_of_code::scaffold::{self, DayStatus, Kind, State};
+use advent_of_code::select;
+use advent_of_code::template::{self, Placeholders};
+use std::process;
+
+struct Args {
+    year: Option<u16>,
+    days: Option<Vec<u8>>,
+    template: String,
+    title: Option<String>,
+    answer_type: Option<String>,
+    repair: bool,
+    dry_run: bool,
+    status: bool,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), Err("Not solved".to_string()));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), Err("Not solved".to_string()));
-    }
-}
-"###;
-
-fn parse_args() -> Result<u8, pico_args::Error> {
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    let template = args
+        .opt_value_from_str(["-t", "--template"])?
+        .unwrap_or_else(|| template::DEFAULT.to_string());
+    let title = args.opt_value_from_str("--title")?;
+    let answer_type = args.opt_value_from_str("--answer-type")?;
+    let repair = args.contains("--repair");
+    let dry_run = args.contains("--dry-run");
+    let status = args.contains("--status");
+    let year_option = args.opt_value_from_fn(["-y", "--year"], select::parse_year)?;
+    let (year, days) = select::year_and_days(&mut args)?;
+    Ok(Args {
+        year: year.or(year_option),
+        days,
+        template,
+        title,
+        answer_type,
+        repair,
+        dry_run,
+        status,
+    })
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+fn render_module(args: &Args, year: u16, day: u8) -> String {
+    match template::load(&template::dir(), &args.template) {
+        Ok(template) => template::render(
+            &template,
+            &Placeholders {
+                year,
+                day,
+                title: args.title.clone().unwrap_or_else(|| "Untitled".to_string()),
+                answer_type: args
+                    .answer_type
+                    .clone()
+                    .unwrap_or_else(|| template::DEFAULT_ANSWER_TYPE.to_string()),
+            },
+        ),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn print_status(year: Option<u16>) {
+    let src = &advent_of_code::src_dir();
+    let years = match year {
+        Some(year) => vec![year],
+        None => scaffold::years(src),
+    };
+    let statuses: Vec<DayStatus> = years
+        .iter()
+        .flat_map(|&year| (1..=25).map(move |day| DayStatus::scan(src, year, day)))
+        .filter(|status| status.is_started())
+        .collect();
+
+    if statuses.is_empty() {
+        println!(
+            "No day scaffolded yet, start with `cargo scaffold {} 1`.",
+            year.unwrap_or_else(client::current_year)
+        );
+    } else {
+        print!("{}", scaffold::render_table(&statuses));
+    }
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
-        }
-    };
-
-    let day_padded = format!("{:02}", day);
-
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
-
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    let args = match parse_args() {
+        Ok(args) => args,
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!("Failed to process arguments: {}", e);
             process::exit(1);
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+    let day = match args.days.as_deref() {
+        Some(&[day]) => day,
+        None if args.status => {
+            print_status(args.year);
+            return;
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+        _ => {
+            eprintln!(
+                "Need to specify a single day (as integer). example: `cargo scaffold 2022 7`"
+            );
             process::exit(1);
         }
+    };
+    let year = args.year.unwrap_or_else(client::current_year);
+
+    let status = DayStatus::scan(&advent_of_code::src_dir(), year, day);
+    let module = status.get(Kind::Module);
+    if module.state != State::Missing && !args.repair {
+        eprintln!(
+            "Module file \"{}\" already exists, pass `--repair` to create only the missing files of day {} of {}.",
+            module.path.display(),
+            day,
+            year
+        );
+        process::exit(1);
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    let content = match module.state {
+        State::Missing => render_module(&args, year, day),
+        _ => String::new(),
+    };
+
+    for file in status.missing() {
+        let description = match file.kind {
+            Kind::Module => "module file".to_string(),
+            kind => format!("empty {} file", kind.name()),
+        };
+        if args.dry_run {
+            println!("Would create {} \"{}\"", description, file.path.display());
+            continue;
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
-            process::exit(1);
+        match scaffold::create(file, &content) {
+            Ok(_) => println!("Created {} \"{}\"", description, file.path.display()),
+            Err(e) => {
+                eprintln!("Failed to create {} file: {}", file.kind.name(), e);
+                process::exit(1);
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
-        }
+    if status.missing().next().is_none() {
+        println!(
+            "Nothing to create, all files of day {} of {} exist.",
+            day, year
+        );
     }
 
     println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    if args.status {
+        print_status(Some(year));
+    } else {
+        print!(
+            "{}",
+            scaffold::render_table(&[DayStatus::scan(&advent_of_code::src_dir(), year, day)])
+        );
+    }
+
+    if !args.dry_run {
+        println!("---");
+        println!(
+            "🎄 Type `cargo solve {} {}` to run your solution.",
+            year, day
+        );
+    }
 }
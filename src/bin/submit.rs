@@ -0,0 +1,142 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Answers;
+use advent_of_code::client::{self, Client, Submission};
+use advent_of_code::input::Source;
+use advent_of_code::registry::{Outcome, Registry};
+use advent_of_code::{description, select};
+use std::fs;
+use std::process;
+
+// generated by `build.rs`: one module per `src/yYYYY/NN.rs` plus `register_days`.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let part = args.value_from_fn("--part", select::parse_part)?;
+    let year_option = args.opt_value_from_fn(["-y", "--year"], select::parse_year)?;
+    let (year, day) = select::year_and_day(&mut args)?;
+
+    let remaining = args.finish();
+    if !remaining.is_empty() {
+        return Err(pico_args::Error::ArgumentParsingFailed {
+            cause: format!("unknown argument(s) {:?}", remaining),
+        });
+    }
+
+    Ok(Args {
+        day,
+        part,
+        year: year.or(year_option),
+    })
+}
+
+fn exit_with_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+/// Rewrites the description of a module that has one, so it includes the solved part.
+fn refresh_description(client: &Client, year: u16, day: u8) {
+    let module_path = advent_of_code::module_path(year, day);
+    let has_description = fs::read_to_string(&module_path)
+        .map(|source| description::has_description(&source))
+        .unwrap_or(false);
+    if !has_description {
+        return;
+    }
+
+    let refreshed = client
+        .puzzle(year, day)
+        .map_err(|e| e.to_string())
+        .and_then(|page| description::update(&module_path, &page).map_err(|e| e.to_string()));
+    match refreshed {
+        Ok(()) => println!(
+            "🎄 Refreshed the puzzle description in \"{}\".",
+            module_path.display()
+        ),
+        Err(e) => eprintln!("could not refresh the puzzle description: {}", e),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with_error(format!(
+            "Failed to process arguments: {}, example: `cargo submit 2022 1 --part 2`",
+            e
+        )),
+    };
+
+    let year = args.year.unwrap_or_else(client::current_year);
+
+    if let Some(answer) = Answers::load(year, args.day).get(args.part) {
+        println!(
+            "🎄 Part {} of day {:02} of {} is already solved, the answer was {}.",
+            args.part, args.day, year, answer
+        );
+        return;
+    }
+
+    let mut registry = Registry::new();
+    register_days(&mut registry);
+    if registry.get(year, args.day).is_none() {
+        exit_with_error(format!(
+            "Day {:02} of {} is not implemented, create it with `cargo scaffold {} {}`.",
+            args.day, year, year, args.day
+        ));
+    }
+
+    // the recorded answer is a confirmed answer, which belongs to the default input only.
+    let input = match Source::resolve(year, args.day, None, None).read(year, args.day) {
+        Ok(input) => input,
+        Err(e) if e.is_missing() => exit_with_error(format!(
+            "input missing, run `cargo download {} {}`.\n{}",
+            year, args.day, e
+        )),
+        Err(e) => exit_with_error(e),
+    };
+
+    let answer = match registry.run(year, args.day, args.part, &input) {
+        Outcome::Solved { answer, .. } => answer,
+        Outcome::Errored { kind, error, .. } => {
+            exit_with_error(format!("not solved ({} error).\n{}", kind.as_str(), error))
+        }
+        Outcome::Panicked { message } => exit_with_error(format!("panicked.\n{}", message)),
+        Outcome::MissingInput { error } => exit_with_error(error),
+        Outcome::NotImplemented => exit_with_error("not implemented."),
+    };
+
+    let client = Client::from_env().unwrap_or_else(|e| exit_with_error(e));
+
+    println!(
+        "Submitting {} as the answer to part {} of day {} of {}",
+        answer, args.part, args.day, year
+    );
+
+    let submission = client
+        .submit(year, args.day, args.part, &answer)
+        .unwrap_or_else(|e| exit_with_error(format!("could not submit answer: {}", e)));
+    println!("{}", submission);
+
+    if submission != Submission::Correct {
+        process::exit(1);
+    }
+
+    match Answers::record(year, args.day, args.part, &answer) {
+        Ok(()) => println!(
+            "🎄 Recorded the answer in \"{}\".",
+            Answers::path(year, args.day).display()
+        ),
+        Err(e) => eprintln!("could not record the answer: {}", e),
+    }
+    refresh_description(&client, year, args.day);
+}
@@ -0,0 +1,196 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// What is known about a downloaded input, stored next to it in `NN.meta`:
+///
+/// ```text
+/// year: 2022
+/// day: 1
+/// checksum: fnv1a64:fc17b183ee074373
+/// downloaded_at: 1669870800
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub checksum: String,
+    /// Seconds since the unix epoch.
+    pub downloaded_at: u64,
+}
+
+impl Entry {
+    pub fn parse(content: &str) -> Option<Entry> {
+        let value = |key: &str| {
+            content.lines().find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                (name.trim() == key).then(|| value.trim())
+            })
+        };
+
+        Some(Entry {
+            year: value("year")?.parse().ok()?,
+            day: value("day")?.parse().ok()?,
+            checksum: value("checksum")?.to_string(),
+            downloaded_at: value("downloaded_at")?.parse().ok()?,
+        })
+    }
+
+    pub fn render(&self) -> String {
+        let mut content = String::new();
+        writeln!(content, "year: {}", self.year).unwrap();
+        writeln!(content, "day: {}", self.day).unwrap();
+        writeln!(content, "checksum: {}", self.checksum).unwrap();
+        writeln!(content, "downloaded_at: {}", self.downloaded_at).unwrap();
+        content
+    }
+}
+
+/// The state of the cached input of a day, as far as a download is concerned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Lookup {
+    /// Nothing worth keeping, the input can be downloaded.
+    Missing,
+    /// The input was downloaded for this year and is unchanged since.
+    Cached { input: String, entry: Entry },
+    /// The input has content that a download would lose.
+    Conflict { reason: String },
+}
+
+/// Downloaded inputs of a folder, `NN.txt` with a `NN.meta` sidecar each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl AsRef<Path>) -> InputCache {
+        InputCache {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("{:02}.txt", day))
+    }
+
+    pub fn meta_path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("{:02}.meta", day))
+    }
+
+    pub fn lookup(&self, year: u16, day: u8) -> Lookup {
+        let input = match fs::read_to_string(self.input_path(day)) {
+            Ok(input) if !input.trim().is_empty() => input,
+            _ => return Lookup::Missing,
+        };
+
+        let entry = fs::read_to_string(self.meta_path(day))
+            .ok()
+            .and_then(|content| Entry::parse(&content));
+
+        let reason = match entry {
+            None => "was not downloaded by `cargo download` or its metadata is missing".to_string(),
+            Some(entry) if entry.year != year => {
+                format!("was downloaded for {}, not {}", entry.year, year)
+            }
+            Some(entry) if entry.checksum != checksum(&input) => {
+                "was edited since it was downloaded".to_string()
+            }
+            Some(entry) => return Lookup::Cached { input, entry },
+        };
+
+        Lookup::Conflict {
+            reason: format!("\"{}\" {}", self.input_path(day).display(), reason),
+        }
+    }
+
+    /// Writes `input` and its sidecar, replacing whatever was there.
+    pub fn store(&self, year: u16, day: u8, input: &str, now: u64) -> io::Result<Entry> {
+        let entry = Entry {
+            year,
+            day,
+            checksum: checksum(input),
+            downloaded_at: now,
+        };
+
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.input_path(day), input)?;
+        fs::write(self.meta_path(day), entry.render())?;
+        Ok(entry)
+    }
+}
+
+/// 64-bit FNV-1a, enough to notice edits without pulling in a hashing crate.
+pub fn checksum(content: &str) -> String {
+    let hash = content
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+    format!("fnv1a64:{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temp_cache(name: &str) -> InputCache {
+        let dir = env::temp_dir().join(format!("aoc_cache_{}_{}", name, std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        InputCache::new(dir)
+    }
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(""), "fnv1a64:cbf29ce484222325");
+        assert_eq!(checksum("a"), "fnv1a64:af63dc4c8601ec8c");
+        assert_ne!(checksum("1000\n"), checksum("1000\n\n"));
+    }
+
+    #[test]
+    fn test_entry_roundtrip() {
+        let entry = Entry {
+            year: 2022,
+            day: 1,
+            checksum: checksum("a"),
+            downloaded_at: 1669870800,
+        };
+
+        assert_eq!(Entry::parse(&entry.render()), Some(entry));
+        assert_eq!(Entry::parse("year: 2022\n"), None);
+    }
+
+    #[test]
+    fn test_lookup() {
+        let cache = temp_cache("lookup");
+        assert_eq!(cache.lookup(2022, 1), Lookup::Missing);
+
+        let entry = cache.store(2022, 1, "1000\n", 42).unwrap();
+        assert_eq!(
+            cache.lookup(2022, 1),
+            Lookup::Cached {
+                input: "1000\n".to_string(),
+                entry
+            }
+        );
+        assert!(matches!(cache.lookup(2021, 1), Lookup::Conflict { .. }));
+
+        fs::write(cache.input_path(1), "2000\n").unwrap();
+        assert!(matches!(cache.lookup(2022, 1), Lookup::Conflict { .. }));
+
+        fs::remove_file(cache.meta_path(1)).unwrap();
+        assert!(matches!(cache.lookup(2022, 1), Lookup::Conflict { .. }));
+
+        // an empty input, as created by `cargo scaffold`, is not worth keeping.
+        fs::write(cache.input_path(1), "").unwrap();
+        assert_eq!(cache.lookup(2022, 1), Lookup::Missing);
+
+        fs::remove_dir_all(cache.dir).unwrap();
+    }
+}
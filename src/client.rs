@@ -0,0 +1,397 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::description;
+use crate::http::{self, HttpError, Request, Response};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fmt, fs};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Read from the home directory when `AOC_SESSION` is not set, the same file `aoc-cli` uses.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+/// How the maintainers of Advent of Code can reach whoever runs the tools, an email or a repository url.
+pub const CONTACT_VAR: &str = "AOC_CONTACT";
+
+/// Advent of Code asks automated tools to identify themselves and to leave a way to contact their user.
+pub fn user_agent(contact: &str) -> String {
+    format!(
+        "{} v{} ({})",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        contact
+    )
+}
+
+/// `AOC_CONTACT`, or else the `repository` of `Cargo.toml` if it is set.
+fn contact() -> Option<String> {
+    env::var(CONTACT_VAR)
+        .ok()
+        .or_else(|| Some(env!("CARGO_PKG_REPOSITORY").to_string()))
+        .map(|contact| contact.trim().to_string())
+        .filter(|contact| !contact.is_empty())
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    MissingContact,
+    Http(HttpError),
+    Status { status: u16, body: String },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session token found, set {} or write it to ~/{}",
+                SESSION_VAR, SESSION_FILE
+            ),
+            ClientError::MissingContact => write!(
+                f,
+                "no contact for the User-Agent header, set {} to your email or the url of your repository",
+                CONTACT_VAR
+            ),
+            ClientError::Http(e) => e.fmt(f),
+            ClientError::Status { status, body } => {
+                write!(
+                    f,
+                    "server responded with status {}: {}",
+                    status,
+                    body.trim()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<HttpError> for ClientError {
+    fn from(e: HttpError) -> Self {
+        ClientError::Http(e)
+    }
+}
+
+/// How the server judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// A response that is none of the above, with its text.
+    Unknown(String),
+}
+
+impl Submission {
+    pub fn parse(html: &str) -> Submission {
+        if html.contains("That's the right answer") {
+            Submission::Correct
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Submission::TooHigh
+            } else if html.contains("your answer is too low") {
+                Submission::TooLow
+            } else {
+                Submission::Incorrect
+            }
+        } else if html.contains("You gave an answer too recently") {
+            Submission::RateLimited {
+                wait: parse_wait(html).unwrap_or_default(),
+            }
+        } else if html.contains("You don't seem to be solving the right level") {
+            Submission::WrongLevel
+        } else {
+            Submission::Unknown(description::from_html(html))
+        }
+    }
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "That's the right answer!"),
+            Submission::Incorrect => write!(f, "That's not the right answer."),
+            Submission::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            Submission::TooLow => write!(f, "That's not the right answer, it is too low."),
+            Submission::RateLimited { wait } => write!(
+                f,
+                "You gave an answer too recently, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            Submission::WrongLevel => write!(f, "This part is already solved or not unlocked yet."),
+            Submission::Unknown(text) => write!(f, "Unexpected response:\n{}", text),
+        }
+    }
+}
+
+/// Reads the wait time of "You have 1m 5s left to wait."
+fn parse_wait(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = html[start..].find(" left to wait")? + start;
+
+    html[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+fn url_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Talks to the Advent of Code website, or whatever `base_url` points to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Client {
+    pub base_url: String,
+    pub session: String,
+    pub contact: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, contact: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            contact: contact.to_string(),
+        }
+    }
+
+    /// Uses `AOC_BASE_URL` if set, the session from `AOC_SESSION` or `~/.adventofcode.session`, and refuses to
+    /// make requests without a contact in `AOC_CONTACT` or `Cargo.toml`.
+    pub fn from_env() -> Result<Client, ClientError> {
+        let base_url = env::var(BASE_URL_VAR)
+            .ok()
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| fs::read_to_string(session_file()?).ok())
+            .filter(|session| !session.trim().is_empty())
+            .ok_or(ClientError::MissingSession)?;
+
+        let contact = contact().ok_or(ClientError::MissingContact)?;
+
+        Ok(Client::new(&base_url, &session, &contact))
+    }
+
+    fn request(&self, method: &'static str, path: &str, body: Option<String>) -> Request {
+        let mut headers = vec![
+            ("Cookie".to_string(), format!("session={}", self.session)),
+            ("User-Agent".to_string(), user_agent(&self.contact)),
+        ];
+        if body.is_some() {
+            headers.push((
+                "Content-Type".to_string(),
+                "application/x-www-form-urlencoded".to_string(),
+            ));
+        }
+
+        Request {
+            method,
+            url: format!("{}{}", self.base_url, path),
+            headers,
+            body,
+        }
+    }
+
+    fn send(&self, request: Request) -> Result<Response, ClientError> {
+        let response = http::send(&request)?;
+        if response.is_success() {
+            Ok(response)
+        } else {
+            Err(ClientError::Status {
+                status: response.status,
+                body: response.body,
+            })
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Result<Response, ClientError> {
+        self.send(self.request("GET", path, None))
+    }
+
+    /// The html of a day's puzzle page, which includes part two once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        Ok(self.get(&format!("/{}/day/{}", year, day))?.body)
+    }
+
+    /// Posts `answer` for `part` of a day.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, ClientError> {
+        let body = format!("level={}&answer={}", part, url_encode(answer.trim()));
+        let path = format!("/{}/day/{}/answer", year, day);
+        let response = self.send(self.request("POST", &path, Some(body)))?;
+        Ok(Submission::parse(&response.body))
+    }
+
+    /// The puzzle input of a day, exactly as served.
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        Ok(self.get(&format!("/{}/day/{}/input", year, day))?.body)
+    }
+}
+
+fn session_file() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE))
+}
+
+/// The latest event: this year's from December on, last year's before.
+pub fn current_year() -> u16 {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() / 86_400);
+    let (year, month) = civil_from_days(days as i64);
+    if month == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+/// Year and month of a day since 1970-01-01, after Howard Hinnant's `civil_from_days`.
+fn civil_from_days(days: i64) -> (u16, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year as u16, month as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::tests::mock_server;
+
+    #[test]
+    fn test_input() {
+        let (url, server) =
+            mock_server("HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\n1000\n2000\n");
+        let client = Client::new(&format!("{}/", url), "abc\n", "me@example.com");
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc\r\n"));
+        assert!(request.contains(&format!(
+            "\r\nUser-Agent: advent_of_code v{} (me@example.com)\r\n",
+            env!("CARGO_PKG_VERSION")
+        )));
+    }
+
+    #[test]
+    fn test_input_error_status() {
+        let (url, server) = mock_server(
+            "HTTP/1.1 400 Bad Request\r\nContent-Length: 28\r\n\r\nPuzzle inputs differ by user",
+        );
+        let client = Client::new(&url, "expired", "me@example.com");
+
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::Status { status: 400, .. })
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let (url, server) = mock_server(
+            "HTTP/1.1 200 OK\r\n\r\n<main><article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article></main>",
+        );
+        let client = Client::new(&url, "abc", "me@example.com");
+
+        assert_eq!(
+            client.submit(2022, 1, 2, "24 000").unwrap(),
+            Submission::Correct
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nContent-Type: application/x-www-form-urlencoded\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=24%20000"));
+    }
+
+    #[test]
+    fn test_parse_submission() {
+        let article = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+
+        assert_eq!(
+            Submission::parse(&article("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again.")),
+            Submission::TooHigh
+        );
+        assert_eq!(
+            Submission::parse(&article(
+                "That's not the right answer; your answer is too low."
+            )),
+            Submission::TooLow
+        );
+        assert_eq!(
+            Submission::parse(&article("That's not the right answer.  If you're stuck, make sure you're using the full input data.")),
+            Submission::Incorrect
+        );
+        assert_eq!(
+            Submission::parse(&article("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait. <a href=\"/2022/day/1\">[Return to Day 1]</a>")),
+            Submission::RateLimited {
+                wait: Duration::from_secs(65)
+            }
+        );
+        assert_eq!(
+            Submission::parse(&article(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Submission::WrongLevel
+        );
+        assert_eq!(
+            Submission::parse(&article("Something <em>new</em>.")),
+            Submission::Unknown("Something *new*.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_civil_from_days() {
+        assert_eq!(civil_from_days(0), (1970, 1));
+        assert_eq!(civil_from_days(19_326), (2022, 11));
+        assert_eq!(civil_from_days(19_327), (2022, 12));
+        assert_eq!(civil_from_days(19_358), (2023, 1));
+    }
+}
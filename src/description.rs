@@ -0,0 +1,394 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Converts the puzzle descriptions of a puzzle page to markdown.
+///
+/// Every `<article>` is converted, followed by the "Your puzzle answer was" paragraph of a solved part.
+/// Everything else on the page, like navigation and share links, is left out.
+pub fn from_html(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let end = match rest[start..].find("</article>") {
+            Some(end) => start + end,
+            None => break,
+        };
+        let article = &rest[start..end];
+        let body = article.find('>').map_or("", |index| &article[index + 1..]);
+        markdown.push_str(&convert(body));
+
+        rest = &rest[end + "</article>".len()..];
+        let answer = rest.trim_start();
+        if answer.starts_with("<p>Your puzzle answer was") {
+            let answer_end = answer.find("</p>").map_or(answer.len(), |index| index + 4);
+            markdown.push_str(&convert(&answer[..answer_end]));
+        }
+    }
+
+    markdown.trim_end().to_string()
+}
+
+/// The example of a part as found in its description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleBlock {
+    pub part: u8,
+    /// The first code block after "For example", if the part introduces one.
+    pub input: Option<String>,
+    /// The last emphasised code value of the part, which usually is the example's answer.
+    pub answer: Option<String>,
+}
+
+/// Finds the example of every part in a description converted by [`from_html`].
+pub fn extract_examples(markdown: &str) -> Vec<ExampleBlock> {
+    markdown
+        .split("\\--- Part Two ---")
+        .enumerate()
+        .map(|(index, text)| {
+            // the answers of solved parts are emphasised too, but are not about the example.
+            let text = text
+                .split("Your puzzle answer was")
+                .next()
+                .unwrap_or_default();
+
+            ExampleBlock {
+                part: index as u8 + 1,
+                input: example_input(text),
+                answer: emphasised_values(text).pop(),
+            }
+        })
+        .collect()
+}
+
+fn example_input(text: &str) -> Option<String> {
+    let after = &text[text.find("For example")?..];
+    let start = after.find("```\n")? + 4;
+    let end = after[start..].find("```")? + start;
+    Some(after[start..end].to_string())
+}
+
+/// Values rendered as `` `*value*` `` or `` *`value`* ``.
+fn emphasised_values(text: &str) -> Vec<String> {
+    let mut values = vec![];
+    let mut rest = text;
+
+    while let Some(start) = rest.find(['`', '*']) {
+        let open = match &rest[start..] {
+            tail if tail.starts_with("`*") => "`*",
+            tail if tail.starts_with("*`") => "*`",
+            _ => {
+                rest = &rest[start + 1..];
+                continue;
+            }
+        };
+        let close: String = open.chars().rev().collect();
+        let value_start = start + open.len();
+        match rest[value_start..].find(&close) {
+            Some(length) if length > 0 => {
+                values.push(rest[value_start..value_start + length].to_string());
+                rest = &rest[value_start + length + close.len()..];
+            }
+            _ => rest = &rest[value_start..],
+        }
+    }
+
+    values
+}
+
+/// Renders `markdown` as an inner doc comment of a module.
+pub fn to_doc_comment(markdown: &str) -> String {
+    markdown
+        .lines()
+        .map(|line| match line {
+            "" => "//!\n".to_string(),
+            line => format!("//! {}\n", line),
+        })
+        .collect()
+}
+
+/// Whether `source` starts with a description block.
+pub fn has_description(source: &str) -> bool {
+    source.starts_with("//!")
+}
+
+/// Replaces the leading `//!` block of `source` with `doc`, or inserts `doc` if there is none.
+pub fn replace(source: &str, doc: &str) -> String {
+    let code_start: usize = source
+        .split_inclusive('\n')
+        .take_while(|line| line.starts_with("//!"))
+        .map(str::len)
+        .sum();
+    let code = source[code_start..].trim_start_matches(['\n', '\r']);
+
+    format!("{}\n{}", doc, code)
+}
+
+/// Refreshes the description at the top of the module at `path` from a puzzle page.
+pub fn update(path: &Path, html: &str) -> io::Result<()> {
+    let source = fs::read_to_string(path)?;
+    let doc = to_doc_comment(&from_html(html));
+    fs::write(path, replace(&source, &doc))
+}
+
+/// A piece of html: a start tag with its `href`, an end tag or text.
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Start(&'a str, Option<&'a str>),
+    End(&'a str),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                // an unterminated tag is not markup, keep it as text.
+                let end = match rest.find('>') {
+                    Some(end) => end,
+                    None => {
+                        tokens.push(Token::Text(rest));
+                        break;
+                    }
+                };
+                let tag = &rest[1..end];
+                let name_end = tag
+                    .find(|c: char| c.is_whitespace() || c == '/')
+                    .unwrap_or(tag.len());
+                if let Some(name) = tag.strip_prefix('/') {
+                    tokens.push(Token::End(name.trim()));
+                } else if name_end > 0 {
+                    tokens.push(Token::Start(&tag[..name_end], attribute(tag, "href")));
+                }
+                rest = &rest[end + 1..];
+            }
+            Some(index) => {
+                tokens.push(Token::Text(&rest[..index]));
+                rest = &rest[index..];
+            }
+            None => {
+                tokens.push(Token::Text(rest));
+                rest = "";
+            }
+        }
+    }
+
+    tokens
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = tag[start..].find('"')? + start;
+    Some(&tag[start..end])
+}
+
+fn convert(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut hrefs: Vec<Option<&str>> = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Start("h2", _) => markdown.push('\\'),
+            Token::End("h2") => markdown.push_str("\n----------\n\n"),
+            Token::End("p") => markdown.push_str("\n\n"),
+            Token::Start("pre", _) => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            Token::End("pre") => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            Token::Start("li", _) => markdown.push_str("* "),
+            Token::End("li") => markdown.push('\n'),
+            Token::End("ul") => markdown.push('\n'),
+            Token::Start("em", _) | Token::End("em") if !in_pre => markdown.push('*'),
+            Token::Start("code", _) | Token::End("code") if !in_pre => markdown.push('`'),
+            Token::Start("a", href) => {
+                hrefs.push(href);
+                markdown.push('[');
+            }
+            Token::End("a") => match hrefs.pop().flatten() {
+                Some(href) => markdown.push_str(&format!("]({})", decode_entities(href))),
+                None => markdown.push(']'),
+            },
+            Token::Start("br", _) => markdown.push('\n'),
+            Token::Text(text) if in_pre => markdown.push_str(&decode_entities(text)),
+            Token::Text(text) => {
+                let mut text = decode_entities(&text.replace('\n', " "));
+                // whitespace between block elements.
+                if markdown.is_empty() || markdown.ends_with('\n') {
+                    text = text.trim_start().to_string();
+                }
+                // a literal `*` would read as emphasis.
+                markdown.push_str(&text.replace('*', "\\*"));
+            }
+            _ => {}
+        }
+    }
+
+    markdown
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2><p>Santa's reindeer need a lot of <a href="/2018/day/25">magical energy</a> and <em>fifty stars</em>.</p>
+<pre><code>1000
+2000 &lt;3
+</code></pre>
+<ul>
+<li>The first Elf carries <code>1000</code>, a total of <code><em>6000</em></code> Calories.</li>
+<li>In the example above, this is <em><code>24000</code></em>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>69177</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>By the time you <span title="hover">calculate</span> the answer...</p>
+</article>
+<p>Both parts of this puzzle are complete! They provide two gold stars: **</p>
+</main>"#;
+
+    #[test]
+    fn test_from_html() {
+        assert_eq!(
+            from_html(PAGE),
+            "\\--- Day 1: Calorie Counting ---
+----------
+
+Santa's reindeer need a lot of [magical energy](/2018/day/25) and *fifty stars*.
+
+```
+1000
+2000 <3
+```
+
+* The first Elf carries `1000`, a total of `*6000*` Calories.
+* In the example above, this is *`24000`*.
+
+Your puzzle answer was `69177`.
+
+\\--- Part Two ---
+----------
+
+By the time you calculate the answer..."
+        );
+    }
+
+    #[test]
+    fn test_tokenize_unterminated_tag() {
+        assert_eq!(tokenize("a <"), vec![Token::Text("a "), Token::Text("<")]);
+        assert_eq!(tokenize("<é"), vec![Token::Text("<é")]);
+        assert_eq!(
+            tokenize("<p>x</p"),
+            vec![
+                Token::Start("p", None),
+                Token::Text("x"),
+                Token::Text("</p")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_examples() {
+        let markdown = "\\--- Day 1: Calorie Counting ---
+----------
+
+For example, suppose the Elves end up with the following list:
+
+```
+1000
+2000
+```
+
+* The first Elf is carrying a total of `*6000*` Calories.
+
+In the example above, this is *`24000`* (carried by the fourth Elf).
+
+Find the Elf carrying the most Calories. *How many total Calories is that Elf carrying?*
+
+Your puzzle answer was `69177`.
+
+\\--- Part Two ---
+----------
+
+The sum of the Calories carried by these three elves is `*45000*`.
+
+Your puzzle answer was `207456`.";
+
+        assert_eq!(
+            extract_examples(markdown),
+            vec![
+                ExampleBlock {
+                    part: 1,
+                    input: Some("1000\n2000\n".to_string()),
+                    answer: Some("24000".to_string()),
+                },
+                ExampleBlock {
+                    part: 2,
+                    input: None,
+                    answer: Some("45000".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_examples_from_page() {
+        let examples = extract_examples(&from_html(PAGE));
+
+        assert_eq!(examples[0].input, None);
+        assert_eq!(examples[0].answer, Some("24000".to_string()));
+        assert_eq!(examples[1].answer, None);
+    }
+
+    #[test]
+    fn test_to_doc_comment() {
+        assert_eq!(
+            to_doc_comment("\\--- Part Two ---\n\nText"),
+            "//! \\--- Part Two ---\n//!\n//! Text\n"
+        );
+    }
+
+    #[test]
+    fn test_replace() {
+        let code = "use std::fmt;\n\nfn main() {}\n";
+
+        assert_eq!(replace(code, "//! New\n"), format!("//! New\n\n{}", code));
+        assert_eq!(
+            replace(&format!("//! Old\n//!\n//! Old\n\n{}", code), "//! New\n"),
+            format!("//! New\n\n{}", code)
+        );
+
+        let crlf = "use std::fmt;\r\n\r\nfn main() {}\r\n";
+        assert_eq!(
+            replace(
+                &format!("//! Old\r\n//!\r\n//! Old\r\n\r\n{}", crlf),
+                "//! New\n"
+            ),
+            format!("//! New\n\n{}", crlf)
+        );
+        assert_eq!(replace("//! Old", "//! New\n"), "//! New\n\n");
+    }
+}
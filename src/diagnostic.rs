@@ -0,0 +1,124 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::ops::Range;
+
+/// A parse error at a byte span of the input, rendered with the line it happened in.
+///
+/// ```text
+/// error: expected ','
+///  --> line 2, column 4
+///   |
+/// 2 | 2-4;6-8
+///   |    ^
+///   = in section assignment
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub message: String,
+    /// The bytes of the input the error points at. An empty span points in between two characters.
+    pub span: Range<usize>,
+    /// The nom contexts the error happened in, innermost first.
+    pub contexts: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(message: impl Into<String>, span: Range<usize>) -> Diagnostic {
+        Diagnostic {
+            message: message.into(),
+            span,
+            contexts: vec![],
+        }
+    }
+
+    /// Renders the message, the line number, the source line and a caret under the span, followed by the contexts.
+    ///
+    /// Spans outside of the input point at its end, spans over several lines are cut at the end of their first line.
+    pub fn render(&self, input: &str) -> String {
+        let start = char_boundary(input, self.span.start);
+        let line_start = input[..start].rfind('\n').map_or(0, |newline| newline + 1);
+        let line_end = input[start..]
+            .find('\n')
+            .map_or(input.len(), |newline| start + newline);
+        let line = input[line_start..line_end].trim_end_matches('\r');
+        let end = char_boundary(input, self.span.end.clamp(start, line_start + line.len()));
+
+        let number = input[..line_start].matches('\n').count() + 1;
+        let column = input[line_start..start].chars().count() + 1;
+        let gutter = " ".repeat(number.to_string().len());
+        // Tabs are kept, so the caret lines up with the source line in any terminal.
+        let padding: String = input[line_start..start]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(input[start..end].chars().count().max(1));
+
+        let mut rendered = format!(
+            "error: {}\n{}--> line {}, column {}\n{} |\n{} | {}\n{} | {}{}",
+            self.message, gutter, number, column, gutter, number, line, gutter, padding, carets
+        );
+        for context in &self.contexts {
+            rendered.push_str(&format!("\n{} = in {}", gutter, context));
+        }
+        rendered
+    }
+}
+
+/// The closest character boundary at or before `offset`, at most the end of `input`.
+fn char_boundary(input: &str, offset: usize) -> usize {
+    let mut offset = offset.min(input.len());
+    while !input.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let input = "2-4,6-8\n2-4;6-8\n";
+        let mut diagnostic = Diagnostic::new("expected ','", 11..11);
+        diagnostic.contexts = vec!["section assignment".to_string()];
+        assert_eq!(
+            diagnostic.render(input),
+            "error: expected ','\n \
+             --> line 2, column 4\n  \
+             |\n\
+             2 | 2-4;6-8\n  \
+             |    ^\n  \
+             = in section assignment"
+        );
+    }
+
+    #[test]
+    fn test_render_span() {
+        let input = "line one\n\tline two\r\nline three";
+        let diagnostic = Diagnostic::new("bad word", 15..18);
+        assert_eq!(
+            diagnostic.render(input),
+            "error: bad word\n --> line 2, column 7\n  |\n2 | \tline two\n  | \t     ^^^"
+        );
+
+        let multiline = Diagnostic::new("bad lines", 14..40);
+        assert!(multiline
+            .render(input)
+            .ends_with("2 | \tline two\n  | \t    ^^^^"));
+    }
+
+    #[test]
+    fn test_render_at_end() {
+        let diagnostic = Diagnostic::new("expected a digit", 100..100);
+        assert_eq!(
+            diagnostic.render("12\n"),
+            "error: expected a digit\n --> line 2, column 1\n  |\n2 | \n  | ^"
+        );
+        assert_eq!(
+            Diagnostic::new("expected a digit", 1..1).render("é"),
+            "error: expected a digit\n --> line 1, column 1\n  |\n1 | é\n  | ^"
+        );
+    }
+}
@@ -0,0 +1,428 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::diagnostic::Diagnostic;
+use crate::ReadError;
+use nom::error::{VerboseError, VerboseErrorKind};
+use std::error::Error as StdError;
+use std::num::ParseIntError;
+use std::{fmt, io};
+
+/// Where in the input a parse error happened, counting from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: Option<usize>,
+}
+
+impl Location {
+    /// The location of `fragment`, which must be a slice of `input`, like the remaining input of a nom error.
+    pub fn of(input: &str, fragment: &str) -> Option<Location> {
+        let offset = (fragment.as_ptr() as usize).checked_sub(input.as_ptr() as usize)?;
+        if offset + fragment.len() > input.len() {
+            return None;
+        }
+        let before = input.get(..offset)?;
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        Some(Location {
+            line: before.matches('\n').count() + 1,
+            column: Some(before[line_start..].chars().count() + 1),
+        })
+    }
+
+    /// The byte offset of this location in `input`, the start of the line if there is no column.
+    pub fn offset(&self, input: &str) -> Option<usize> {
+        let line_start = match self.line {
+            0 => return None,
+            1 => 0,
+            line => input.match_indices('\n').nth(line - 2)?.0 + 1,
+        };
+        let line = &input[line_start..];
+        let line = &line[..line.find('\n').unwrap_or(line.len())];
+        match self.column {
+            None => Some(line_start),
+            Some(column) => {
+                let in_line = line
+                    .char_indices()
+                    .map(|(offset, _)| offset)
+                    .chain(std::iter::once(line.len()))
+                    .nth(column.checked_sub(1)?)?;
+                Some(line_start + in_line)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "line {}, column {}", self.line, column),
+            None => write!(f, "line {}", self.line),
+        }
+    }
+}
+
+/// The kind of an [`Error`], so the runner can tell bad input from a bug in the solution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Parse,
+    Logic,
+    Input,
+}
+
+impl ErrorKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorKind::Parse => "parse",
+            ErrorKind::Logic => "logic",
+            ErrorKind::Input => "input",
+        }
+    }
+}
+
+/// Why a part could not be solved.
+#[derive(Debug)]
+pub enum Error {
+    /// The input does not have the expected format.
+    Parse {
+        message: String,
+        location: Option<Location>,
+        /// The nom contexts the error happened in, innermost first.
+        contexts: Vec<String>,
+        source: Option<Box<dyn StdError + Send + Sync>>,
+    },
+    /// The input was understood, but the solution found no answer, e.g. `"not solved"`.
+    Logic(String),
+    /// The input could not be read.
+    Input(ReadError),
+    /// Reading the input failed while it was streamed, see [`crate::stream`].
+    Io(io::Error),
+}
+
+impl Error {
+    pub fn parse(message: impl Into<String>) -> Error {
+        Error::Parse {
+            message: message.into(),
+            location: None,
+            contexts: vec![],
+            source: None,
+        }
+    }
+
+    pub fn logic(message: impl Into<String>) -> Error {
+        Error::Logic(message.into())
+    }
+
+    /// A parse error for a nom error on `input`, located at the remaining input of its first entry.
+    ///
+    /// The message describes the innermost parser that failed, the contexts are kept for [`render`].
+    pub fn nom(input: &str, error: VerboseError<&str>) -> Error {
+        let location = error
+            .errors
+            .first()
+            .and_then(|(remaining, _)| Location::of(input, remaining));
+        let contexts: Vec<String> = error
+            .errors
+            .iter()
+            .filter_map(|(_, kind)| match kind {
+                VerboseErrorKind::Context(context) => Some(context.to_string()),
+                _ => None,
+            })
+            .collect();
+        let message = error
+            .errors
+            .iter()
+            .find_map(|(_, kind)| nom_message(kind))
+            .or_else(|| {
+                contexts
+                    .first()
+                    .map(|context| format!("invalid {}", context))
+            })
+            .unwrap_or_else(|| "invalid input".to_string());
+        Error::Parse {
+            message,
+            location,
+            contexts,
+            source: None,
+        }
+    }
+
+    /// Sets the location of a parse error, other kinds are returned unchanged.
+    pub fn at(mut self, at: Location) -> Error {
+        if let Error::Parse { location, .. } = &mut self {
+            *location = Some(at);
+        }
+        self
+    }
+
+    /// Sets the line of a parse error, keeping its column, e.g. for an error in a single line of the input.
+    pub fn at_line(self, line: usize) -> Error {
+        let column = self.location().and_then(|location| location.column);
+        self.at(Location { line, column })
+    }
+
+    /// Keeps `cause` as the source of a parse error, other kinds are returned unchanged.
+    pub fn with_source(mut self, cause: impl StdError + Send + Sync + 'static) -> Error {
+        if let Error::Parse { source, .. } = &mut self {
+            *source = Some(Box::new(cause));
+        }
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Parse { .. } => ErrorKind::Parse,
+            Error::Logic(_) => ErrorKind::Logic,
+            Error::Input(_) | Error::Io(_) => ErrorKind::Input,
+        }
+    }
+
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Error::Parse { location, .. } => *location,
+            _ => None,
+        }
+    }
+
+    /// The diagnostic of a parse error with a location in `input`, see [`render`].
+    ///
+    /// Without a column the whole line is marked, e.g. for an error from [`Error::at_line`].
+    pub fn diagnostic(&self, input: &str) -> Option<Diagnostic> {
+        let Error::Parse {
+            message,
+            location: Some(location),
+            contexts,
+            ..
+        } = self
+        else {
+            return None;
+        };
+        let start = location.offset(input)?;
+        let end = match location.column {
+            Some(_) => start,
+            None => input[start..]
+                .find('\n')
+                .map_or(input.len(), |end| start + end),
+        };
+        Some(Diagnostic {
+            message: message.clone(),
+            span: start..end,
+            contexts: contexts.clone(),
+        })
+    }
+}
+
+/// Describes what the parser of a nom error expected, `None` for a context.
+fn nom_message(kind: &VerboseErrorKind) -> Option<String> {
+    use nom::error::ErrorKind as Nom;
+
+    let message = match kind {
+        VerboseErrorKind::Context(_) => return None,
+        VerboseErrorKind::Char(c) => return Some(format!("expected {:?}", c)),
+        VerboseErrorKind::Nom(Nom::Eof) => "expected the end of the input",
+        VerboseErrorKind::Nom(Nom::Tag) => "unexpected text",
+        VerboseErrorKind::Nom(Nom::Digit) => "expected a digit",
+        VerboseErrorKind::Nom(Nom::Space | Nom::MultiSpace) => "expected whitespace",
+        VerboseErrorKind::Nom(Nom::CrLf) => "expected a line ending",
+        VerboseErrorKind::Nom(Nom::Alt) => "none of the alternatives matched",
+        VerboseErrorKind::Nom(Nom::MapRes) => "invalid value",
+        VerboseErrorKind::Nom(kind) => {
+            return Some(format!("{} failed", kind.description().to_lowercase()))
+        }
+    };
+    Some(message.to_string())
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                message,
+                location: Some(location),
+                ..
+            } => write!(f, "{}: {}", location, message),
+            Error::Parse { message, .. } => write!(f, "{}", message),
+            Error::Logic(message) => write!(f, "{}", message),
+            Error::Input(e) => write!(f, "{}", e),
+            Error::Io(_) => write!(f, "could not read the input"),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Parse { source, .. } => source
+                .as_deref()
+                .map(|source| source as &(dyn StdError + 'static)),
+            Error::Logic(_) => None,
+            Error::Input(e) => Some(e),
+            Error::Io(e) => Some(e),
+        }
+    }
+}
+
+/// Errors are equal if they are of the same kind and render the same, so tests can compare results.
+impl PartialEq for Error {
+    fn eq(&self, other: &Error) -> bool {
+        self.kind() == other.kind() && report(self) == report(other)
+    }
+}
+
+impl From<ReadError> for Error {
+    fn from(e: ReadError) -> Self {
+        Error::Input(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Error::parse("invalid number").with_source(e)
+    }
+}
+
+/// Quick errors while working on a solution, like `Err("not solved".into())`.
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Logic(message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::Logic(message.to_string())
+    }
+}
+
+/// Renders an error followed by every error in its source chain.
+pub fn report(error: &dyn StdError) -> String {
+    let mut report = error.to_string();
+    push_causes(&mut report, error);
+    report
+}
+
+/// Like [`report`], but renders a parse error with a location as a [`Diagnostic`] of `input`.
+pub fn render(error: &Error, input: &str) -> String {
+    match error.diagnostic(input) {
+        Some(diagnostic) => {
+            let mut rendered = diagnostic.render(input);
+            push_causes(&mut rendered, error);
+            rendered
+        }
+        None => report(error),
+    }
+}
+
+fn push_causes(report: &mut String, error: &dyn StdError) {
+    let mut source = error.source();
+    while let Some(cause) = source {
+        report.push_str("\ncaused by: ");
+        report.push_str(&cause.to_string());
+        source = cause.source();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete::digit1;
+    use nom::error::{context, VerboseErrorKind};
+    use nom::Finish;
+
+    #[test]
+    fn test_location_of() {
+        let input = "12\n34\nab";
+        assert_eq!(
+            Location::of(input, &input[7..]),
+            Some(Location {
+                line: 3,
+                column: Some(2)
+            })
+        );
+        assert_eq!(
+            Location::of(input, input).map(|l| l.to_string()),
+            Some("line 1, column 1".to_string())
+        );
+        assert_eq!(Location::of(&input[..2], &input[1..]), None);
+    }
+
+    #[test]
+    fn test_nom() {
+        let input = "12\nab";
+        let error = context("number", digit1::<_, VerboseError<&str>>)(&input[3..])
+            .finish()
+            .unwrap_err();
+        assert!(matches!(
+            error.errors.last(),
+            Some((_, VerboseErrorKind::Context("number")))
+        ));
+
+        let error = Error::nom(input, error);
+        assert_eq!(error.kind(), ErrorKind::Parse);
+        assert_eq!(
+            error.location(),
+            Some(Location {
+                line: 2,
+                column: Some(1)
+            })
+        );
+        assert!(error.to_string().starts_with("line 2, column 1: "));
+    }
+
+    #[test]
+    fn test_location_offset() {
+        let input = "12\n3é4\n";
+        let at = |line, column| Location { line, column }.offset(input);
+        assert_eq!(at(1, Some(1)), Some(0));
+        assert_eq!(at(2, Some(3)), Some(6));
+        assert_eq!(at(2, Some(4)), Some(7));
+        assert_eq!(at(2, None), Some(3));
+        assert_eq!(at(2, Some(5)), None);
+        assert_eq!(at(4, None), None);
+        assert_eq!(at(0, None), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "1\nx2\n";
+        let error = Error::parse("invalid round").at_line(2);
+        assert_eq!(
+            render(&error, input),
+            "error: invalid round\n --> line 2, column 1\n  |\n2 | x2\n  | ^^"
+        );
+
+        let cause = "x".parse::<u32>().unwrap_err();
+        let error = Error::parse("invalid number")
+            .at(Location {
+                line: 2,
+                column: Some(1),
+            })
+            .with_source(cause);
+        assert!(render(&error, input).ends_with("  | ^\ncaused by: invalid digit found in string"));
+
+        let error = Error::logic("Not solved");
+        assert_eq!(render(&error, input), "Not solved");
+    }
+
+    #[test]
+    fn test_report() {
+        let cause = "x".parse::<u32>().unwrap_err();
+        let error = Error::parse("invalid round").at_line(3).with_source(cause);
+        assert_eq!(
+            report(&error),
+            "line 3: invalid round\ncaused by: invalid digit found in string"
+        );
+
+        let error: Error = "not solved".into();
+        assert_eq!(error.kind(), ErrorKind::Logic);
+        assert_eq!(report(&error), "not solved");
+        assert_eq!(report(&error.at_line(1)), "not solved");
+    }
+}
@@ -0,0 +1,340 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::{Answers, Verdict};
+use crate::description::ExampleBlock;
+use crate::registry::{self, Solver};
+use crate::{read_day_file, ReadError};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A named example of a day, `src/examples/<year>/NN/<name>.txt`.
+///
+/// Its expected answers live next to it in `<name>.answers`, in the format of `src/answers/<year>/NN.txt`.
+/// Parts without an expected answer are not checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: Answers,
+}
+
+pub fn dir(year: u16, day: u8) -> PathBuf {
+    crate::data_dir("examples", year).join(format!("{:02}", day))
+}
+
+/// Loads the named examples of a day, sorted by name. A day without an examples folder has none.
+pub fn load(year: u16, day: u8) -> Result<Vec<Example>, ReadError> {
+    load_from(&dir(year, day), year, day)
+}
+
+fn load_from(dir: &Path, year: u16, day: u8) -> Result<Vec<Example>, ReadError> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(vec![]),
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect();
+    names.sort();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let input = read_day_file(dir.join(format!("{}.txt", name)), "examples", year, day)?;
+            let answers =
+                match read_day_file(dir.join(format!("{}.answers", name)), "examples", year, day) {
+                    Ok(content) => Answers::parse(&content),
+                    Err(e) if e.is_missing() => Answers::default(),
+                    Err(e) => return Err(e),
+                };
+            Ok(Example {
+                name,
+                input,
+                answers,
+            })
+        })
+        .collect()
+}
+
+/// Writes examples extracted from a puzzle description to `dir` as `a.txt`, `b.txt`, ...
+///
+/// A part without an example of its own shares the previous one, which then gets its answer too.
+/// Existing inputs and answers are kept: an example whose file has a different content is skipped.
+/// Returns the files that were written.
+pub fn store_extracted(dir: &Path, blocks: &[ExampleBlock]) -> io::Result<Vec<PathBuf>> {
+    let mut examples: Vec<(String, Answers)> = vec![];
+    for block in blocks {
+        match (&block.input, examples.last_mut()) {
+            (Some(input), Some((last, _))) if input == last => {}
+            (Some(input), _) => examples.push((input.clone(), Answers::default())),
+            (None, Some(_)) => {}
+            (None, None) => continue,
+        }
+        if let (Some(answer), Some((_, answers))) = (&block.answer, examples.last_mut()) {
+            answers.set(block.part, answer.clone());
+        }
+    }
+
+    let mut written = vec![];
+    for ((input, answers), name) in examples.iter().zip('a'..='z') {
+        let input_path = dir.join(format!("{}.txt", name));
+        let answers_path = dir.join(format!("{}.answers", name));
+
+        match fs::read_to_string(&input_path) {
+            Ok(existing) if existing == *input => {}
+            Ok(existing) if !existing.trim().is_empty() => continue,
+            _ => {
+                fs::create_dir_all(dir)?;
+                fs::write(&input_path, input)?;
+                written.push(input_path);
+            }
+        }
+
+        let mut merged = fs::read_to_string(&answers_path)
+            .map(|content| Answers::parse(&content))
+            .unwrap_or_default();
+        for part in 1..=2 {
+            if let (None, Some(answer)) = (merged.get(part), answers.get(part)) {
+                merged.set(part, answer.to_string());
+            }
+        }
+        if merged != Answers::default() {
+            let content = merged.render();
+            if fs::read_to_string(&answers_path).ok().as_deref() != Some(content.as_str()) {
+                fs::write(&answers_path, content)?;
+                written.push(answers_path);
+            }
+        }
+    }
+
+    Ok(written)
+}
+
+/// Reads the input of the example `name` of a day, panicking if it does not exist.
+pub fn read(year: u16, day: u8, name: &str) -> String {
+    let path = dir(year, day).join(format!("{}.txt", name));
+    match read_day_file(path, "examples", year, day) {
+        Ok(input) => input,
+        Err(e) => panic!("{}", e),
+    }
+}
+
+/// Runs `solver` on every example of a day with an expected answer for `part`.
+///
+/// Panics, listing every mismatch, if any example disagrees or no example has an answer for `part`.
+pub fn check(year: u16, day: u8, part: u8, solver: Solver) {
+    check_all(year, day, part, solver, true);
+}
+
+/// Like [`check`], but passes while no example has an answer for `part` yet, as for a freshly scaffolded day.
+pub fn check_if_present(year: u16, day: u8, part: u8, solver: Solver) {
+    check_all(year, day, part, solver, false);
+}
+
+fn check_all(year: u16, day: u8, part: u8, solver: Solver, required: bool) {
+    let examples = load(year, day).unwrap_or_else(|e| panic!("{}", e));
+    let failures = check_examples(&examples, part, solver);
+
+    match failures {
+        Some(failures) if failures.is_empty() => {}
+        None if !required => eprintln!(
+            "day {:02} of {} has no example with an answer for part {} yet, skipped",
+            day, year, part
+        ),
+        Some(failures) => panic!(
+            "day {:02} of {} part {} failed {} example(s):\n{}",
+            day,
+            year,
+            part,
+            failures.len(),
+            failures.join("\n")
+        ),
+        None => panic!(
+            "day {:02} of {} has no example with an answer for part {} in \"{}\"",
+            day,
+            year,
+            part,
+            dir(year, day).display()
+        ),
+    }
+}
+
+/// The mismatches of the examples with an answer for `part`, `None` if there are no such examples.
+fn check_examples(examples: &[Example], part: u8, solver: Solver) -> Option<Vec<String>> {
+    let checked: Vec<&Example> = examples
+        .iter()
+        .filter(|example| example.answers.get(part).is_some())
+        .collect();
+    if checked.is_empty() {
+        return None;
+    }
+
+    let failures = checked
+        .into_iter()
+        .filter_map(|example| {
+            let outcome = registry::run_solver(solver, &example.input);
+            match example.answers.verify(part, &outcome) {
+                Verdict::Correct => None,
+                _ => Some(format!(
+                    "  {}: expected {:?}, got {:?}",
+                    example.name,
+                    example.answers.get(part).unwrap_or_default(),
+                    outcome
+                )),
+            }
+        })
+        .collect();
+
+    Some(failures)
+}
+
+/// Generates one test per part that checks every example of the day, see [`examples::check`](crate::examples::check).
+///
+/// With `skip_missing`, a part without an example answer passes, see
+/// [`examples::check_if_present`](crate::examples::check_if_present). The templates use it so that scaffolding a day
+/// does not break `cargo test`.
+#[macro_export]
+macro_rules! example_tests {
+    ($year:expr, $day:expr, $part_one:ident, $part_two:ident, skip_missing) => {
+        #[test]
+        fn test_examples_part_one() {
+            advent_of_code::examples::check_if_present($year, $day, 1, |input| {
+                $part_one(input).map(|answer| answer.to_string())
+            });
+        }
+
+        #[test]
+        fn test_examples_part_two() {
+            advent_of_code::examples::check_if_present($year, $day, 2, |input| {
+                $part_two(input).map(|answer| answer.to_string())
+            });
+        }
+    };
+    ($year:expr, $day:expr, $part_one:ident, $part_two:ident) => {
+        #[test]
+        fn test_examples_part_one() {
+            advent_of_code::examples::check($year, $day, 1, |input| {
+                $part_one(input).map(|answer| answer.to_string())
+            });
+        }
+
+        #[test]
+        fn test_examples_part_two() {
+            advent_of_code::examples::check($year, $day, 2, |input| {
+                $part_two(input).map(|answer| answer.to_string())
+            });
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(name: &str, input: &str, answers: &str) -> Example {
+        Example {
+            name: name.to_string(),
+            input: input.to_string(),
+            answers: Answers::parse(answers),
+        }
+    }
+
+    #[test]
+    fn test_check_examples() {
+        let examples = vec![
+            example("a", "abc", "--- part 1 ---\n3\n"),
+            example("b", "abcd", "--- part 1 ---\n5\n--- part 2 ---\n4\n"),
+        ];
+        let solver: Solver = |input| Ok(input.len().to_string());
+
+        let failures = check_examples(&examples, 1, solver).unwrap();
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].starts_with("  b: expected \"5\""));
+
+        assert_eq!(check_examples(&examples, 2, solver), Some(vec![]));
+        assert_eq!(check_examples(&examples[..1], 2, solver), None);
+    }
+
+    #[test]
+    fn test_store_extracted() {
+        let dir = std::env::temp_dir().join(format!("aoc_examples_{}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        let block = |part: u8, input: Option<&str>, answer: Option<&str>| ExampleBlock {
+            part,
+            input: input.map(str::to_string),
+            answer: answer.map(str::to_string),
+        };
+
+        let written = store_extracted(
+            &dir,
+            &[
+                block(1, Some("1\n2\n"), Some("3")),
+                block(2, None, Some("2")),
+            ],
+        )
+        .unwrap();
+        assert_eq!(written, vec![dir.join("a.txt"), dir.join("a.answers")]);
+        assert_eq!(
+            load_from(&dir, 2022, 1).unwrap(),
+            vec![example(
+                "a",
+                "1\n2\n",
+                "--- part 1 ---\n3\n--- part 2 ---\n2\n"
+            )]
+        );
+
+        // a confirmed answer is kept and a new example of part two gets its own file.
+        fs::write(dir.join("a.answers"), "--- part 1 ---\n4\n").unwrap();
+        let written = store_extracted(
+            &dir,
+            &[
+                block(1, Some("1\n2\n"), Some("3")),
+                block(2, Some("5\n"), Some("5")),
+            ],
+        )
+        .unwrap();
+        assert_eq!(written, vec![dir.join("b.txt"), dir.join("b.answers")]);
+        assert_eq!(
+            load_from(&dir, 2022, 1).unwrap(),
+            vec![
+                example("a", "1\n2\n", "--- part 1 ---\n4\n"),
+                example("b", "5\n", "--- part 2 ---\n5\n"),
+            ]
+        );
+
+        // hand-edited examples are left alone.
+        fs::write(dir.join("a.txt"), "edited\n").unwrap();
+        fs::remove_file(dir.join("a.answers")).unwrap();
+        let written = store_extracted(&dir, &[block(1, Some("1\n2\n"), Some("3"))]).unwrap();
+        assert!(written.is_empty());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_missing_dir() {
+        assert_eq!(
+            load_from(Path::new("does/not/exist"), 2022, 1).unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_check_without_examples() {
+        // no examples folder for this day, the template's tests pass.
+        check_if_present(1999, 25, 1, |_| Ok("1".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "has no example with an answer for part 1")]
+    fn test_check_requires_examples() {
+        check(1999, 25, 1, |_| Ok("1".to_string()));
+    }
+}
@@ -2,3 +2,395 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+pub mod geom;
+pub mod parse;
+
+use crate::error::Location;
+use geom::{Direction, Point};
+
+/// A position in a grid, with `(0, 0)` in the top left corner.
+pub type Position = Point<usize>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    /// A row that is not as wide as the first one, counting from 1.
+    Ragged {
+        row: usize,
+        width: usize,
+        expected: usize,
+    },
+    InvalidCell {
+        position: Position,
+        cell: char,
+    },
+    /// Cells that do not fill `width * height`.
+    Size {
+        width: usize,
+        height: usize,
+        cells: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid has no cells"),
+            GridError::Ragged {
+                row,
+                width,
+                expected,
+            } => write!(
+                f,
+                "row {} of the grid has {} cells, expected {}",
+                row, width, expected
+            ),
+            GridError::InvalidCell { position, cell } => {
+                write!(f, "invalid cell {:?} at {}", cell, position)
+            }
+            GridError::Size {
+                width,
+                height,
+                cells,
+            } => write!(f, "{} cells do not fill a {}x{} grid", cells, width, height),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// A parse error located at the offending cell or row.
+impl From<GridError> for crate::Error {
+    fn from(e: GridError) -> Self {
+        let location = match e {
+            GridError::Ragged { row, .. } => Some(Location {
+                line: row,
+                column: None,
+            }),
+            GridError::InvalidCell { position, .. } => Some(Location {
+                line: position.y + 1,
+                column: Some(position.x + 1),
+            }),
+            GridError::Empty | GridError::Size { .. } => None,
+        };
+        let error = crate::Error::parse(e.to_string());
+        match location {
+            Some(location) => error.at(location),
+            None => error,
+        }
+    }
+}
+
+/// A rectangular grid stored row by row. Positions outside of it are `None` instead of a panic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Result<Grid<T>, GridError> {
+        if width.checked_mul(height) != Some(cells.len()) {
+            return Err(GridError::Size {
+                width,
+                height,
+                cells: cells.len(),
+            });
+        }
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Parses one row per line and one cell per character, e.g. `|c| c.to_digit(10)`.
+    pub fn parse(input: &str, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, GridError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.lines().enumerate() {
+            let row_start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or(GridError::InvalidCell {
+                    position: Point::new(x, y),
+                    cell: c,
+                })?);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(expected) if expected != row_width => {
+                    return Err(GridError::Ragged {
+                        row: y + 1,
+                        width: row_width,
+                        expected,
+                    })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                cells,
+                width,
+                height,
+            }),
+            _ => Err(GridError::Empty),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.y * self.width + position.x)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Replaces the cell, returning `false` if the position is outside of the grid.
+    pub fn set(&mut self, position: Position, value: T) -> bool {
+        match self.get_mut(position) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The next position in `direction`, if it is still in the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        position.step(direction).filter(|&next| self.contains(next))
+    }
+
+    /// The up to four positions north, east, south and west of `position`.
+    pub fn neighbours(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The up to eight positions around `position`, including the diagonals.
+    pub fn neighbours_with_diagonals(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// Every cell from `position` towards the edge, excluding `position` itself.
+    pub fn ray(
+        &self,
+        position: Position,
+        direction: Direction,
+    ) -> impl Iterator<Item = (Position, &T)> + '_ {
+        std::iter::successors(self.step(position, direction), move |&current| {
+            self.step(current, direction)
+        })
+        .map(|position| (position, &self[position]))
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T> + '_> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {} is outside of the {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {} is outside of the {}x{} grid",
+                position, width, height
+            ),
+        }
+    }
+}
+
+/// Prints the cells of every row next to each other, with a newline after each row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(x: usize, y: usize) -> Position {
+        Point::new(x, y)
+    }
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_rectangular() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(p(2, 0)), Some(&3));
+        assert_eq!(grid.get(p(0, 1)), Some(&4));
+        assert_eq!(grid.get(p(0, 2)), None);
+        assert_eq!(grid.get(p(3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let tall = digits("12\n34\n56");
+        assert_eq!((tall.width(), tall.height()), (2, 3));
+        assert_eq!(tall[p(1, 2)], 6);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Grid::parse("12\n3\n", |c| c.to_digit(10)),
+            Err(GridError::Ragged {
+                row: 2,
+                width: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            Grid::parse("12\n3x\n", |c| c.to_digit(10)),
+            Err(GridError::InvalidCell {
+                position: p(1, 1),
+                cell: 'x'
+            })
+        );
+        assert_eq!(Grid::parse("", |c| c.to_digit(10)), Err(GridError::Empty));
+        assert!(Grid::from_vec(2, 2, vec![1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_set() {
+        let mut grid = Grid::new(3, 2, '.');
+        assert!(grid.set(p(2, 1), '#'));
+        assert!(!grid.set(p(1, 2), '#'));
+        grid[p(0, 0)] = '#';
+        assert_eq!(grid.to_string(), "#..\n..#\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = digits("123\n456\n");
+        assert_eq!(
+            grid.neighbours(p(0, 0)).collect::<Vec<_>>(),
+            [p(1, 0), p(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours(p(1, 1)).collect::<Vec<_>>(),
+            [p(1, 0), p(2, 1), p(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours_with_diagonals(p(1, 1)).collect::<Vec<_>>(),
+            [p(1, 0), p(2, 0), p(2, 1), p(0, 1), p(0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = digits("1234\n5678\n");
+        let cells = |position, direction| {
+            grid.ray(position, direction)
+                .map(|(_, &cell)| cell)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(cells(p(0, 0), Direction::East), [2, 3, 4]);
+        assert_eq!(cells(p(3, 1), Direction::West), [7, 6, 5]);
+        assert_eq!(cells(p(2, 1), Direction::North), [3]);
+        assert_eq!(cells(p(0, 1), Direction::NorthEast), [2]);
+        assert_eq!(cells(p(0, 0), Direction::North), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("123\n456\n");
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).unwrap().collect::<Vec<_>>(), [&3, &6]);
+        assert!(grid.column(3).is_none());
+        assert_eq!(
+            grid.iter().find(|(_, &cell)| cell == 5).map(|(p, _)| p),
+            Some(p(1, 1))
+        );
+    }
+}
@@ -0,0 +1,326 @@
+//! Points and directions on a 2D plane, shared by grids and free-moving puzzles.
+//!
+//! `y` grows downwards like the rows of the input, so `North` is towards the first row.
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The integer types a [`Point`] is made of.
+pub trait Coordinate: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(impl Coordinate for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$t>::checked_sub(self, other)
+            }
+        })*
+    };
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+fn distance<T: Coordinate>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// One step from `from` towards `to`, or `from` itself if they are equal.
+fn step_towards<T: Coordinate>(from: T, to: T) -> T {
+    match from.cmp(&to) {
+        std::cmp::Ordering::Less => from + T::ONE,
+        std::cmp::Ordering::Equal => from,
+        std::cmp::Ordering::Greater => from - T::ONE,
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    pub const ORIGIN: Point<T> = Point::new(T::ZERO, T::ZERO);
+
+    /// The number of orthogonal steps between the points, `|dx| + |dy|`.
+    pub fn manhattan(self, other: Point<T>) -> T {
+        distance(self.x, other.x) + distance(self.y, other.y)
+    }
+
+    /// The number of king moves between the points, `max(|dx|, |dy|)`.
+    pub fn chebyshev(self, other: Point<T>) -> T {
+        distance(self.x, other.x).max(distance(self.y, other.y))
+    }
+
+    /// Moves at most one step on each axis towards `target`, diagonally if needed.
+    pub fn step_towards(self, target: Point<T>) -> Point<T> {
+        Point::new(
+            step_towards(self.x, target.x),
+            step_towards(self.y, target.y),
+        )
+    }
+
+    /// The neighbouring point in `direction`, or `None` if it does not fit in `T`, e.g. below `0` for `usize`.
+    pub fn step(self, direction: Direction) -> Option<Point<T>> {
+        let axis = |value: T, delta: i8| match delta {
+            1 => value.checked_add(T::ONE),
+            -1 => value.checked_sub(T::ONE),
+            _ => Some(value),
+        };
+        let (dx, dy) = direction.delta();
+        Some(Point::new(axis(self.x, dx)?, axis(self.y, dy)?))
+    }
+
+    /// Converts between coordinate types, e.g. from a signed point to a grid position.
+    pub fn try_convert<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Point<T> {
+        Point { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> (T, T) {
+        (point.x, point.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, rhs: Point<T>) -> Point<T> {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, rhs: Point<T>) -> Point<T> {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Point<T>) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Point<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, rhs: T) -> Point<T> {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    fn delta(self) -> (i8, i8) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::NorthEast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    /// The step in this direction as a signed point, e.g. `(0, -1)` for north.
+    pub fn offset<T: Coordinate + Neg<Output = T>>(self) -> Point<T> {
+        let axis = |delta: i8| match delta {
+            1 => T::ONE,
+            -1 => -T::ONE,
+            _ => T::ZERO,
+        };
+        let (dx, dy) = self.delta();
+        Point::new(axis(dx), axis(dy))
+    }
+
+    fn turn(self, eighths: usize) -> Direction {
+        let index = Direction::ALL.iter().position(|&d| d == self).unwrap();
+        Direction::ALL[(index + eighths) % 8]
+    }
+
+    /// Turns 90° clockwise, e.g. from north to east.
+    pub fn rotate_right(self) -> Direction {
+        self.turn(2)
+    }
+
+    /// Turns 90° counter-clockwise, e.g. from north to west.
+    pub fn rotate_left(self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        let (dx, dy) = self.delta();
+        dx != 0 && dy != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 4);
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Point::new(5usize, 0).manhattan(Point::new(2, 7)), 10);
+        assert_eq!(Point::<u8>::ORIGIN.chebyshev(Point::new(2, 7)), 7);
+    }
+
+    #[test]
+    fn test_step_towards() {
+        let tail = Point::new(0, 0);
+        assert_eq!(tail.step_towards(Point::new(2, 1)), Point::new(1, 1));
+        assert_eq!(tail.step_towards(Point::new(0, -2)), Point::new(0, -1));
+        assert_eq!(tail.step_towards(tail), tail);
+        assert_eq!(
+            Point::new(3usize, 3).step_towards(Point::new(0, 5)),
+            Point::new(2, 4)
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        let origin = Point::<usize>::ORIGIN;
+        assert_eq!(origin.step(Direction::South), Some(Point::new(0, 1)));
+        assert_eq!(origin.step(Direction::North), None);
+        assert_eq!(
+            Point::new(0i32, 0).step(Direction::NorthWest),
+            Some(Point::new(-1, -1))
+        );
+        for direction in Direction::ALL {
+            assert_eq!(
+                Point::new(5i64, 5).step(direction),
+                Some(Point::new(5, 5) + direction.offset())
+            );
+        }
+    }
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Direction::North.rotate_right(), Direction::East);
+        assert_eq!(Direction::West.rotate_right(), Direction::North);
+        assert_eq!(Direction::North.rotate_left(), Direction::West);
+        assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+        for direction in Direction::ALL {
+            assert_eq!(direction.rotate_right().rotate_left(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(
+                direction.opposite().offset::<i32>(),
+                -direction.offset::<i32>()
+            );
+        }
+        assert!(!Direction::East.is_diagonal());
+        assert!(Direction::NorthEast.is_diagonal());
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(
+            Point::new(2i64, 3).try_convert::<usize>(),
+            Some(Point::new(2, 3))
+        );
+        assert_eq!(Point::new(-1i64, 3).try_convert::<usize>(), None);
+        assert_eq!(
+            Point::new(2usize, 3).try_convert::<i32>(),
+            Some(Point::new(2, 3))
+        );
+        assert_eq!(Point::from((1, 2)), Point::new(1, 2));
+        assert_eq!(<(i32, i32)>::from(Point::new(1, 2)), (1, 2));
+        assert_eq!(Point::new(1, -2) * 3, Point::new(3, -6));
+    }
+}
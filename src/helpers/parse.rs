@@ -0,0 +1,154 @@
+//! Building blocks for the nom parsers of the days, e.g. `lines_of(unsigned::<u32>)`.
+//!
+//! Every parser works on `&str` with a `VerboseError`, so failures can be rendered with their line and column.
+use crate::error::Location;
+use crate::Error;
+use nom::branch::alt;
+use nom::character::complete::{char, digit1, line_ending};
+use nom::combinator::{map_res, opt, recognize};
+use nom::error::{context, VerboseError};
+use nom::multi::separated_list1;
+use nom::sequence::{pair, terminated};
+use nom::{Finish, IResult};
+use std::str::FromStr;
+
+/// The result of every parser in this module.
+pub type Res<'a, U> = IResult<&'a str, U, VerboseError<&'a str>>;
+
+/// Digits without a sign, e.g. `unsigned::<u32>`. Fails if the number does not fit in `T`.
+pub fn unsigned<T: FromStr>(input: &str) -> Res<'_, T> {
+    context("unsigned integer", map_res(digit1, str::parse))(input)
+}
+
+/// Digits with an optional leading `-` or `+`, e.g. `signed::<i64>`. Fails if the number does not fit in `T`.
+pub fn signed<T: FromStr>(input: &str) -> Res<'_, T> {
+    let sign = opt(alt((char('-'), char('+'))));
+    context(
+        "signed integer",
+        map_res(recognize(pair(sign, digit1)), str::parse),
+    )(input)
+}
+
+/// One or more lines, each parsed by `parser`. The newline after the last line is left in the input.
+///
+/// A line that `parser` fails on after consuming part of it is an error, instead of the end of the list.
+pub fn lines_of<'a, O>(
+    parser: impl FnMut(&'a str) -> Res<'a, O>,
+) -> impl FnMut(&'a str) -> Res<'a, Vec<O>> {
+    separated_list1(line_ending, committed(parser))
+}
+
+/// One or more blocks separated by a blank line, like the groups of numbers of 2022 day 1.
+///
+/// `parser` must not consume the newline after the last line of its block, which [`lines_of`] does not.
+pub fn blocks_of<'a, O>(
+    parser: impl FnMut(&'a str) -> Res<'a, O>,
+) -> impl FnMut(&'a str) -> Res<'a, Vec<O>> {
+    separated_list1(pair(line_ending, line_ending), committed(parser))
+}
+
+/// Turns an error of `parser` after it consumed some input into a failure, so lists do not backtrack over it.
+fn committed<'a, O>(
+    mut parser: impl FnMut(&'a str) -> Res<'a, O>,
+) -> impl FnMut(&'a str) -> Res<'a, O> {
+    move |input| match parser(input) {
+        Err(nom::Err::Error(e))
+            if e.errors
+                .first()
+                .is_some_and(|(remaining, _)| remaining.len() < input.len()) =>
+        {
+            Err(nom::Err::Failure(e))
+        }
+        result => result,
+    }
+}
+
+/// Runs `parser` on the whole input, allowing only a final newline after it.
+///
+/// Anything else that is left is an error located where `parser` stopped, so a malformed line cannot silently
+/// truncate the input. A parser that stops at the newline before a bad line, like [`lines_of`], is reported at the
+/// start of that line.
+pub fn parse_all<'a, O>(
+    parser: impl FnMut(&'a str) -> Res<'a, O>,
+    input: &'a str,
+) -> Result<O, Error> {
+    let (rest, parsed) = terminated(parser, opt(line_ending))(input)
+        .finish()
+        .map_err(|e| Error::nom(input, e))?;
+    if rest.is_empty() {
+        return Ok(parsed);
+    }
+
+    let location = Location::of(input, rest);
+    let line = location
+        .and_then(|location| input.lines().nth(location.line - 1))
+        .unwrap_or(rest);
+    let error = Error::parse(format!("unexpected input in {:?}", line));
+    Err(match location {
+        Some(location) => error.at(location),
+        None => error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+    use nom::sequence::separated_pair;
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(unsigned::<u32>("42 rest"), Ok((" rest", 42)));
+        assert!(unsigned::<u32>("-42").is_err());
+        assert!(unsigned::<u8>("256").is_err());
+        assert_eq!(signed::<i32>("-42,"), Ok((",", -42)));
+        assert_eq!(signed::<i64>("+7"), Ok(("", 7)));
+        assert!(signed::<i8>("-129").is_err());
+        assert!(signed::<i32>("-").is_err());
+    }
+
+    #[test]
+    fn test_lines_and_blocks() {
+        assert_eq!(
+            lines_of(unsigned::<u32>)("1\n2\n3\n"),
+            Ok(("\n", vec![1, 2, 3]))
+        );
+        assert_eq!(
+            blocks_of(lines_of(unsigned::<u32>))("1\n2\n\n3\n"),
+            Ok(("\n", vec![vec![1, 2], vec![3]]))
+        );
+    }
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(
+            parse_all(lines_of(signed::<i32>), "1\n-2\n"),
+            Ok(vec![1, -2])
+        );
+
+        let error = parse_all(lines_of(signed::<i32>), "1\n-2\n3x\n4\n").unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::Parse);
+        assert_eq!(
+            error.location(),
+            Some(Location {
+                line: 3,
+                column: Some(2)
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 2: unexpected input in \"3x\""
+        );
+
+        let error = parse_all(lines_of(unsigned::<u32>), "1\nabc\n2").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: unexpected input in \"abc\""
+        );
+        assert!(parse_all(unsigned::<u32>, "").is_err());
+
+        let pair = |input| separated_pair(unsigned::<u32>, char(','), unsigned::<u32>)(input);
+        let error = parse_all(lines_of(pair), "1,2\n3;4\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: expected ','");
+    }
+}
@@ -0,0 +1,191 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug)]
+pub enum HttpError {
+    Url(String),
+    Io(io::Error),
+    /// The server could not be reached or did not speak HTTP, e.g. a refused connection or a failed TLS handshake.
+    Transport(String),
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::Url(url) => write!(f, "invalid url \"{}\"", url),
+            HttpError::Io(e) => write!(f, "request failed: {}", e),
+            HttpError::Transport(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<io::Error> for HttpError {
+    fn from(e: io::Error) -> Self {
+        HttpError::Io(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: &'static str,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Sends `request` and reads the whole response, `http://` for local mock servers and `https://` with rustls.
+///
+/// Error statuses are returned as a `Response` like any other, redirects are not followed.
+pub fn send(request: &Request) -> Result<Response, HttpError> {
+    let agent = ureq::AgentBuilder::new()
+        .timeout(TIMEOUT)
+        .redirects(0)
+        .build();
+
+    let mut call = agent.request(request.method, &request.url);
+    for (name, value) in &request.headers {
+        call = call.set(name, value);
+    }
+    let result = match &request.body {
+        Some(body) => call.send_string(body),
+        None => call.call(),
+    };
+
+    let response = match result {
+        Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+        Err(ureq::Error::Transport(transport)) => {
+            return Err(match transport.kind() {
+                ureq::ErrorKind::InvalidUrl | ureq::ErrorKind::UnknownScheme => {
+                    HttpError::Url(request.url.clone())
+                }
+                _ => HttpError::Transport(transport.to_string()),
+            })
+        }
+    };
+
+    Ok(Response {
+        status: response.status(),
+        body: response.into_string()?,
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves `response` once on a random local port, returning its base url and the received request.
+    pub(crate) fn mock_server(response: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buffer = [0; 4096];
+            // read until the headers and the announced body are complete.
+            loop {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some(split) = text.find("\r\n\r\n") {
+                    let length = text
+                        .lines()
+                        .find_map(|line| line.strip_prefix("Content-Length: "))
+                        .map_or(0, |length| length.trim().parse().unwrap());
+                    if request.len() >= split + 4 + length {
+                        break;
+                    }
+                }
+                if read == 0 {
+                    break;
+                }
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn test_invalid_url() {
+        let request = |url: &str| Request {
+            method: "GET",
+            url: url.to_string(),
+            headers: vec![],
+            body: None,
+        };
+
+        assert!(matches!(
+            send(&request("ftp://example.com")),
+            Err(HttpError::Url(_))
+        ));
+        assert!(matches!(
+            send(&request("http://:80/")),
+            Err(HttpError::Url(_))
+        ));
+    }
+
+    #[test]
+    fn test_send_error_status() {
+        let (url, server) = mock_server(
+            "HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nnope\r\n0\r\n\r\n",
+        );
+        let response = send(&Request {
+            method: "GET",
+            url,
+            headers: vec![],
+            body: None,
+        })
+        .unwrap();
+        server.join().unwrap();
+
+        assert!(!response.is_success());
+        assert_eq!(response.status, 404);
+        assert_eq!(response.body, "nope");
+    }
+
+    #[test]
+    fn test_send() {
+        let (url, server) = mock_server("HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok");
+        let response = send(&Request {
+            method: "POST",
+            url: format!("{}/submit", url),
+            headers: vec![("Cookie".to_string(), "session=abc".to_string())],
+            body: Some("level=1".to_string()),
+        })
+        .unwrap();
+
+        assert!(response.is_success());
+        assert_eq!(response.body, "ok");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /submit HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=1"));
+    }
+}
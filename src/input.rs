@@ -0,0 +1,163 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::registry::Outcome;
+use crate::{ErrorKind, ReadError};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, fmt};
+
+/// Overrides the folder that puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    File(ReadError),
+    Stdin(io::Error),
+}
+
+impl InputError {
+    pub fn is_missing(&self) -> bool {
+        matches!(self, InputError::File(e) if e.is_missing())
+    }
+
+    /// What a part reports when it could not be run for lack of an input.
+    pub fn outcome(&self) -> Outcome {
+        if self.is_missing() {
+            Outcome::MissingInput {
+                error: self.to_string(),
+            }
+        } else {
+            Outcome::Errored {
+                kind: ErrorKind::Input,
+                error: self.to_string(),
+                elapsed: Duration::ZERO,
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::File(e) => e.fmt(f),
+            InputError::Stdin(error) => write!(f, "could not read input from stdin: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl Source {
+    /// `--input <path>` (`-` for stdin) wins over `$AOC_INPUT_DIR/<year>/NN.txt`, which wins over `src/inputs/<year>/NN.txt`.
+    pub fn resolve(year: u16, day: u8, input: Option<&str>, input_dir: Option<&Path>) -> Source {
+        match input {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+            None => {
+                // relative to the crate instead of the working directory, so binaries work from anywhere.
+                let dir = match input_dir {
+                    Some(dir) => dir.join(year.to_string()),
+                    None => crate::data_dir("inputs", year),
+                };
+                Source::File(dir.join(format!("{:02}.txt", day)))
+            }
+        }
+    }
+
+    /// Resolves the source of a day from `input` and the `AOC_INPUT_DIR` environment variable.
+    pub fn from_env(year: u16, day: u8, input: Option<&str>) -> Source {
+        let input_dir = env::var_os(INPUT_DIR_VAR).filter(|dir| !dir.is_empty());
+        Source::resolve(year, day, input, input_dir.as_deref().map(Path::new))
+    }
+
+    /// Whether this is the input in `src/inputs`, the one the confirmed answers of `src/answers` belong to.
+    pub fn is_default(&self, year: u16, day: u8) -> bool {
+        *self == Source::resolve(year, day, None, None)
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+            Source::File(path) => {
+                crate::read_day_file(path.clone(), "inputs", year, day).map_err(InputError::File)
+            }
+        }
+    }
+
+    /// Opens the input for solvers that read it incrementally, see `--stream`.
+    pub fn open(&self, year: u16, day: u8) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(error) => Err(InputError::File(ReadError {
+                    path: path.clone(),
+                    year,
+                    day,
+                    folder: "inputs".to_string(),
+                    error,
+                })),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(Source::resolve(2022, 5, Some("-"), None), Source::Stdin);
+        assert_eq!(
+            Source::resolve(2022, 5, Some("other.txt"), Some(Path::new("/inputs"))),
+            Source::File(PathBuf::from("other.txt"))
+        );
+        assert_eq!(
+            Source::resolve(2022, 5, None, Some(Path::new("/inputs"))),
+            Source::File(PathBuf::from("/inputs/2022/05.txt"))
+        );
+        assert!(!Source::resolve(2022, 5, None, Some(Path::new("/inputs"))).is_default(2022, 5));
+        assert!(!Source::Stdin.is_default(2022, 5));
+        assert!(Source::resolve(2022, 5, None, None).is_default(2022, 5));
+        assert_eq!(
+            Source::resolve(2021, 12, None, None),
+            Source::File(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("src")
+                    .join("inputs")
+                    .join("2021")
+                    .join("12.txt")
+            )
+        );
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        let source = Source::File(PathBuf::from("does/not/exist.txt"));
+        let error = source.read(2022, 3).unwrap_err();
+
+        assert!(error.is_missing());
+        assert!(error.to_string().starts_with(
+            "could not read the inputs file of day 03 of 2022 at \"does/not/exist.txt\""
+        ));
+        assert!(matches!(error.outcome(), Outcome::MissingInput { .. }));
+    }
+}
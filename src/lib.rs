@@ -3,92 +3,156 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
-use std::fs;
-
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod client;
+pub mod description;
+pub mod diagnostic;
+pub mod error;
+pub mod examples;
 pub mod helpers;
+pub mod http;
+pub mod input;
+pub mod readme;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+pub mod select;
+pub mod stream;
+pub mod template;
+
+pub use error::{Error, ErrorKind};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Exposes a day's solvers as `SOLUTION`, so the runner can call them in-process.
+///
+/// Takes the two part functions, which return `Result<impl Display, impl Into<Error>>`,
+/// or a type implementing [`Solution`]. After `streaming:`, the two parts can also be given as functions that take
+/// an `impl BufRead`, which `--stream` runs instead.
 #[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Result<T, String>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Ok(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                Err(error) => {
-                    println!("not solved.\n{}", error)
-                }
-            }
-        }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+macro_rules! solution {
+    ($year:expr, $day:expr, $part_one:ident, $part_two:ident) => {
+        pub const SOLUTION: advent_of_code::registry::Solution =
+            advent_of_code::registry::Solution {
+                year: $year,
+                day: $day,
+                part_one: |input| advent_of_code::registry::answer($part_one(input)),
+                part_two: |input| advent_of_code::registry::answer($part_two(input)),
+                streaming: None,
+            };
+    };
+    ($year:expr, $day:expr, $part_one:ident, $part_two:ident, streaming: $part_one_stream:ident, $part_two_stream:ident) => {
+        pub const SOLUTION: advent_of_code::registry::Solution =
+            advent_of_code::registry::Solution {
+                year: $year,
+                day: $day,
+                part_one: |input| advent_of_code::registry::answer($part_one(input)),
+                part_two: |input| advent_of_code::registry::answer($part_two(input)),
+                streaming: Some([
+                    |reader| advent_of_code::registry::answer($part_one_stream(reader)),
+                    |reader| advent_of_code::registry::answer($part_two_stream(reader)),
+                ]),
+            };
+    };
+    ($year:expr, $day:expr, $solution:ty) => {
+        pub const SOLUTION: advent_of_code::registry::Solution =
+            advent_of_code::registry::Solution::of::<$solution>($year, $day);
+    };
+}
+
+/// The two parts of a day with their answer types, an alternative to the part functions of `solution!`.
+pub trait Solution {
+    type AnswerOne: fmt::Display;
+    type AnswerTwo: fmt::Display;
+
+    fn part_one(input: &str) -> Result<Self::AnswerOne, Error>;
+    fn part_two(input: &str) -> Result<Self::AnswerTwo, Error>;
+}
+
+/// A day's file that could not be read, see [`try_read_file`].
+#[derive(Debug)]
+pub struct ReadError {
+    pub path: PathBuf,
+    pub year: u16,
+    pub day: u8,
+    pub folder: String,
+    pub error: io::Error,
+}
+
+impl ReadError {
+    pub fn is_missing(&self) -> bool {
+        self.error.kind() == io::ErrorKind::NotFound
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "could not read the {} file of day {:02} of {} at \"{}\": {}",
+            self.folder,
+            self.day,
+            self.year,
+            self.path.display(),
+            self.error
+        )
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// The `src` folder of this crate, so the tools find their files from any working directory.
+pub fn src_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// The module of a day, `src/y2022/05.rs` of this crate.
+pub fn module_path(year: u16, day: u8) -> PathBuf {
+    src_dir()
+        .join(format!("y{}", year))
+        .join(format!("{:02}.rs", day))
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// The folder of a year's files of a kind, e.g. `src/inputs/2022` of this crate.
+pub fn data_dir(folder: &str, year: u16) -> PathBuf {
+    src_dir().join(folder).join(year.to_string())
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Reads `src/<folder>/<year>/NN.txt` of this crate, independent of the working directory.
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> Result<String, ReadError> {
+    let filepath = data_dir(folder, year).join(format!("{:02}.txt", day));
+
+    read_day_file(filepath, folder, year, day)
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
+/// Reads the file at `path`, recording `folder`, `year` and `day` on failure.
+pub fn read_day_file(path: PathBuf, folder: &str, year: u16, day: u8) -> Result<String, ReadError> {
+    fs::read_to_string(&path).map_err(|error| ReadError {
+        path,
+        year,
+        day,
+        folder: folder.to_string(),
+        error,
     })
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Like [`try_read_file`], but panics with the error, for tests that rely on their example.
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    match try_read_file(folder, year, day) {
+        Ok(content) => content,
+        Err(e) => panic!("{}", e),
+    }
 }
 
 #[cfg(test)]
@@ -96,30 +160,13 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    fn test_try_read_file_missing() {
+        let error = try_read_file("examples", 2022, 25).unwrap_err();
+
+        assert!(error.is_missing());
+        assert_eq!(error.year, 2022);
+        assert_eq!(error.day, 25);
+        assert_eq!(error.folder, "examples");
+        assert!(error.path.ends_with("src/examples/2022/25.txt"));
     }
 }
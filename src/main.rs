@@ -2,45 +2,391 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::answers::{self, Verdict};
+use advent_of_code::bench::Bench;
+use advent_of_code::input::Source;
+use advent_of_code::registry::{Outcome, Registry};
+use advent_of_code::report::{Format, PartReport};
+use advent_of_code::{client, readme, select, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+// generated by `build.rs`: one module per `src/yYYYY/NN.rs` plus `register_days`.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+struct Args {
+    year: Option<u16>,
+    days: Option<Vec<u8>>,
+    input: Option<String>,
+    part: Option<u8>,
+    jobs: Option<usize>,
+    stream: bool,
+    update_readme: bool,
+    format: Format,
+    bench: Option<Bench>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+
+    let format = Format::from_args(&mut args);
+    let bench = Bench::from_args(&mut args)?;
+
+    let input = args.opt_value_from_str("--input")?;
+    let part = args.opt_value_from_fn("--part", select::parse_part)?;
+    let jobs = args.opt_value_from_str(["-j", "--jobs"])?;
+    let stream = args.contains("--stream");
+    let update_readme = args.contains("--update-readme");
+    let year_option = args.opt_value_from_fn(["-y", "--year"], select::parse_year)?;
+    let (year, days) = select::year_and_days(&mut args)?;
+
+    let remaining = args.finish();
+    if !remaining.is_empty() {
+        eprintln!("Warning: unknown argument(s): {:?}.", remaining);
+    }
+
+    Ok(Args {
+        year: year.or(year_option),
+        days,
+        input,
+        part,
+        jobs,
+        stream,
+        update_readme,
+        format,
+        bench,
+    })
+}
+
+/// What every day of a run shares.
+struct Run<'a> {
+    registry: &'a Registry,
+    part: Option<u8>,
+    input: Option<&'a str>,
+    bench: Option<&'a Bench>,
+    /// Runs the streaming entry points of the days that have them, see `solution!`.
+    stream: bool,
+}
+
+impl Run<'_> {
+    fn day(&self, year: u16, day: u8) -> Vec<PartReport> {
+        let parts: Vec<u8> = (1..=2)
+            .filter(|number| select::includes_part(self.part, *number))
+            .collect();
+
+        let solution = match self.registry.get(year, day) {
+            Some(solution) => solution,
+            None => {
+                return parts
+                    .into_iter()
+                    .map(|part| PartReport::from_outcome(year, day, part, Outcome::NotImplemented))
+                    .collect()
+            }
+        };
+
+        let source = Source::from_env(year, day, self.input);
+        let mut reports: Vec<PartReport> = if self.stream && solution.streaming.is_some() {
+            parts
+                .into_iter()
+                .map(|part| {
+                    let solver = solution.stream_part(part).unwrap();
+                    match source.open(year, day) {
+                        Ok(mut reader) => {
+                            PartReport::run_stream(year, day, part, solver, &mut reader)
+                        }
+                        Err(e) => PartReport::from_outcome(year, day, part, e.outcome()),
+                    }
+                })
+                .collect()
+        } else {
+            match source.read(year, day) {
+                Ok(input) => parts
+                    .into_iter()
+                    .map(|part| {
+                        let solver = solution.part(part).unwrap();
+                        PartReport::run(year, day, part, solver, &input, self.bench)
+                    })
+                    .collect(),
+                Err(e) => parts
+                    .into_iter()
+                    .map(|part| PartReport::from_outcome(year, day, part, e.outcome()))
+                    .collect(),
+            }
+        };
+
+        // the confirmed answers are for the default input only, any other input has answers of its own.
+        if !source.is_default(year, day) {
+            reports
+                .iter_mut()
+                .for_each(|report| report.verdict = Verdict::Unknown);
+        }
+        reports
+    }
+}
+
+/// Runs the `(year, day)` pairs of `days` on up to `jobs` threads, handing their reports to `on_day` in order.
+fn run_days(
+    run: &Run,
+    days: &[(u16, u8)],
+    jobs: usize,
+    mut on_day: impl FnMut((u16, u8), Vec<PartReport>),
+) {
+    if jobs <= 1 {
+        for &(year, day) in days {
+            on_day((year, day), run.day(year, day));
+        }
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                match days.get(index) {
+                    Some(&(year, day)) => {
+                        if sender.send((index, run.day(year, day))).is_err() {
+                            break;
+                        }
+                    }
+                    None => break,
                 }
-            );
+            });
+        }
+        drop(sender);
+
+        // days can finish out of order, hold them back until all earlier days were handed over.
+        let mut finished: BTreeMap<usize, Vec<PartReport>> = BTreeMap::new();
+        let mut next_to_report = 0;
+        for (index, reports) in receiver {
+            finished.insert(index, reports);
+            while let Some(reports) = finished.remove(&next_to_report) {
+                on_day(days[next_to_report], reports);
+                next_to_report += 1;
+            }
+        }
+    });
+}
+
+/// The latest year with a solution for the day, or the latest year at all when no year solves it.
+fn latest_year(registry: &Registry, day: u8) -> u16 {
+    let years = registry.years();
+    years
+        .iter()
+        .rev()
+        .find(|&&year| registry.get(year, day).is_some())
+        .or(years.last())
+        .copied()
+        .unwrap_or_else(client::current_year)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let format = args.format;
+    let bench = args.bench;
+
+    // days run one after another unless asked otherwise, days running at the same time skew each other's timings.
+    let jobs = args.jobs.unwrap_or(1);
+
+    // the table is rebuilt from the reports of this run, a partial run would drop the rows of every other day.
+    if args.update_readme && (args.year.is_some() || args.days.is_some() || args.part.is_some()) {
+        eprintln!(
+            "`--update-readme` rewrites the whole table, run every day and part: `cargo run --release -- --bench --update-readme`"
+        );
+        process::exit(1);
+    }
+    if args.update_readme && jobs > 1 {
+        eprintln!("`--update-readme` needs timings of days that run on their own, drop `--jobs`");
+        process::exit(1);
+    }
+
+    let mut registry = Registry::new();
+    register_days(&mut registry);
+
+    // without a year, each selected day runs in the latest year that solves it, no selection at all runs every year.
+    let selection: Vec<(u16, u8)> = match (args.year, args.days) {
+        (Some(year), days) => days
+            .unwrap_or_else(|| (1..=25).collect())
+            .into_iter()
+            .map(|day| (year, day))
+            .collect(),
+        (None, Some(days)) => days
+            .into_iter()
+            .map(|day| (latest_year(&registry, day), day))
+            .collect(),
+        (None, None) => registry
+            .years()
+            .into_iter()
+            .flat_map(|year| (1..=25).map(move |day| (year, day)))
+            .collect(),
+    };
+
+    let mut all_reports: Vec<PartReport> = vec![];
+
+    if args.input.is_some() && selection.len() != 1 {
+        eprintln!(
+            "`--input` needs exactly one selected day, example: `cargo solve 2022 5 --input other.txt`"
+        );
+        process::exit(1);
+    }
+
+    if args.stream && args.input.as_deref() == Some("-") && args.part.is_none() {
+        eprintln!(
+            "`--stream` reads stdin only once, pick a part, example: `cat big.txt | cargo solve 2022 1 --stream --input - --part 1`"
+        );
+        process::exit(1);
+    }
+
+    if args.stream && bench.is_some() {
+        eprintln!("`--stream` parts are not benchmarked, drop `--bench` or `--stream`");
+        process::exit(1);
+    }
+
+    let run = Run {
+        registry: &registry,
+        part: args.part,
+        input: args.input.as_deref(),
+        bench: bench.as_ref(),
+        stream: args.stream,
+    };
+
+    run_days(&run, &selection, jobs, |(year, day), reports| {
+        if format == Format::Text {
+            println!("---------------");
+            println!("{}| {} Day {:02} |{}", ANSI_BOLD, year, day, ANSI_RESET);
+            println!("---------------");
 
-            if is_empty {
-                0_f64
+            if registry.get(year, day).is_none() {
+                println!("Not implemented.");
             } else {
-                advent_of_code::parse_exec_time(&output)
+                reports.iter().for_each(|report| report.print(format));
             }
-        })
-        .sum();
+        } else {
+            reports.iter().for_each(|report| report.print(format));
+        }
+
+        all_reports.extend(reports);
+    });
+
+    if format == Format::Text {
+        let total: Duration = all_reports.iter().filter_map(PartReport::time).sum();
+        println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total.as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        );
+    }
+
+    if args.update_readme {
+        match readme::update(&readme::path(), &all_reports) {
+            Ok(()) => eprintln!("🎄 Updated the benchmarks in \"README.md\"."),
+            Err(e) => {
+                eprintln!("Failed to update README.md: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    let verdicts: Vec<Verdict> = all_reports.into_iter().map(|r| r.verdict).collect();
+    answers::exit_on_regression(&verdicts);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::registry::Solution;
+
+    #[test]
+    fn test_run_days_reports_in_order() {
+        let mut registry = Registry::new();
+        registry.register(Solution {
+            year: 2022,
+            day: 2,
+            part_one: |_| Ok("1".to_string()),
+            part_two: |_| Ok("2".to_string()),
+            streaming: None,
+        });
+        let days: Vec<(u16, u8)> = (1..=25).map(|day| (2022, day)).collect();
+
+        for jobs in [1, 4] {
+            let mut reported = vec![];
+            let run = Run {
+                registry: &registry,
+                part: None,
+                input: None,
+                bench: None,
+                stream: false,
+            };
+            run_days(&run, &days, jobs, |(year, day), reports| {
+                assert_eq!(reports.len(), 2);
+                assert!(reports
+                    .iter()
+                    .all(|report| report.year == year && report.day == day));
+                reported.push((year, day));
+            });
+            assert_eq!(reported, days);
+        }
+    }
+
+    #[test]
+    fn test_latest_year_solves_the_day() {
+        let mut registry = Registry::new();
+        for (year, day) in [(2021, 1), (2021, 2), (2022, 1)] {
+            registry.register(Solution {
+                year,
+                day,
+                part_one: |_| Ok("1".to_string()),
+                part_two: |_| Ok("2".to_string()),
+                streaming: None,
+            });
+        }
+
+        assert_eq!(latest_year(&registry, 1), 2022);
+        assert_eq!(latest_year(&registry, 2), 2021);
+        assert_eq!(latest_year(&registry, 3), 2022);
+    }
+
+    #[test]
+    fn test_other_input_is_not_checked() {
+        let mut registry = Registry::new();
+        registry.register(Solution {
+            year: 2022,
+            day: 1,
+            part_one: |_| Ok("1".to_string()),
+            part_two: |_| Ok("2".to_string()),
+            streaming: None,
+        });
+        let example = advent_of_code::data_dir("examples", 2022).join("01.txt");
+        let run = Run {
+            registry: &registry,
+            part: None,
+            input: example.to_str(),
+            bench: None,
+            stream: false,
+        };
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+        let reports = run.day(2022, 1);
+        assert_eq!(reports.len(), 2);
+        assert!(reports
+            .iter()
+            .all(|report| matches!(report.outcome, Outcome::Solved { .. })
+                && report.verdict == Verdict::Unknown));
+    }
 }
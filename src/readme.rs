@@ -0,0 +1,219 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::registry::Outcome;
+use crate::report::PartReport;
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{fs, io};
+
+/// Delimits the region of README.md that `cargo all -- --update-readme` rewrites.
+pub const MARKER: &str = "<!--- benchmarking table --->";
+
+#[derive(Debug)]
+pub enum ReadmeError {
+    Io(io::Error),
+    MissingMarkers,
+}
+
+impl fmt::Display for ReadmeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadmeError::Io(e) => write!(f, "could not access README.md: {}", e),
+            ReadmeError::MissingMarkers => write!(
+                f,
+                "README.md needs two `{}` lines around the table region",
+                MARKER
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for ReadmeError {
+    fn from(e: io::Error) -> Self {
+        ReadmeError::Io(e)
+    }
+}
+
+/// A part earns a star when it is solved and does not contradict a confirmed answer.
+fn is_star(report: &PartReport) -> bool {
+    matches!(report.outcome, Outcome::Solved { .. }) && !report.verdict.is_regression()
+}
+
+fn format_time(time: Option<Duration>) -> String {
+    match time {
+        Some(time) => format!("`{:.2?}`", time),
+        None => "-".to_string(),
+    }
+}
+
+/// Renders one row per implemented day from the reports of a run.
+pub fn render_table(reports: &[PartReport]) -> String {
+    let mut days: BTreeMap<(u16, u8), Vec<&PartReport>> = BTreeMap::new();
+    for report in reports {
+        if report.outcome != Outcome::NotImplemented {
+            days.entry((report.year, report.day))
+                .or_default()
+                .push(report);
+        }
+    }
+
+    let mut table = String::new();
+    table.push_str("\n## Benchmarks\n\n");
+    table.push_str("| Year | Day | Stars | Part 1 | Part 2 | Total |\n");
+    table.push_str("| :---: | :---: | :---: | :---: | :---: | :---: |\n");
+
+    let mut total = Duration::ZERO;
+
+    for ((year, day), parts) in &days {
+        let part = |number: u8| parts.iter().find(|report| report.part == number);
+        let stars = parts.iter().filter(|report| is_star(report)).count();
+        let times: Vec<Duration> = parts.iter().filter_map(|report| report.time()).collect();
+        let day_total = (!times.is_empty()).then(|| times.iter().sum::<Duration>());
+        total += day_total.unwrap_or(Duration::ZERO);
+
+        writeln!(
+            table,
+            "| {} | [Day {}](./src/y{}/{:02}.rs) | {} | {} | {} | {} |",
+            year,
+            day,
+            year,
+            day,
+            "⭐".repeat(stars),
+            format_time(part(1).and_then(|report| report.time())),
+            format_time(part(2).and_then(|report| report.time())),
+            format_time(day_total),
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        table,
+        "\n**Total: {:.2}ms**",
+        total.as_secs_f64() * 1000_f64
+    )
+    .unwrap();
+
+    table
+}
+
+/// Replaces everything between the two markers with `table`.
+pub fn replace_region(readme: &str, table: &str) -> Result<String, ReadmeError> {
+    let start = readme.find(MARKER).ok_or(ReadmeError::MissingMarkers)? + MARKER.len();
+    let end = readme[start..]
+        .find(MARKER)
+        .ok_or(ReadmeError::MissingMarkers)?
+        + start;
+
+    Ok(format!("{}{}{}", &readme[..start], table, &readme[end..]))
+}
+
+/// The README.md of this crate, independent of the working directory like [`crate::data_dir`].
+pub fn path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("README.md")
+}
+
+pub fn update(path: &Path, reports: &[PartReport]) -> Result<(), ReadmeError> {
+    let readme = fs::read_to_string(path)?;
+    let updated = replace_region(&readme, &render_table(reports))?;
+    fs::write(path, updated)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Verdict;
+    use crate::ErrorKind;
+
+    fn report(day: u8, part: u8, outcome: Outcome, verdict: Verdict) -> PartReport {
+        PartReport {
+            year: 2022,
+            day,
+            part,
+            outcome,
+            verdict,
+            stats: None,
+        }
+    }
+
+    fn solved(micros: u64) -> Outcome {
+        Outcome::Solved {
+            answer: "1".to_string(),
+            elapsed: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn test_render_table() {
+        let reports = vec![
+            report(1, 1, solved(10), Verdict::Correct),
+            report(1, 2, solved(20), Verdict::Unknown),
+            report(
+                2,
+                1,
+                solved(1000),
+                Verdict::Wrong {
+                    expected: "2".to_string(),
+                },
+            ),
+            report(
+                2,
+                2,
+                Outcome::Errored {
+                    kind: ErrorKind::Logic,
+                    error: "Not solved".to_string(),
+                    elapsed: Duration::from_micros(5),
+                },
+                Verdict::Unknown,
+            ),
+            report(
+                3,
+                1,
+                Outcome::Panicked {
+                    message: "could not open input file".to_string(),
+                },
+                Verdict::Unknown,
+            ),
+            report(3, 2, Outcome::NotImplemented, Verdict::Unknown),
+        ];
+
+        assert_eq!(
+            render_table(&reports),
+            "
+## Benchmarks
+
+| Year | Day | Stars | Part 1 | Part 2 | Total |
+| :---: | :---: | :---: | :---: | :---: | :---: |
+| 2022 | [Day 1](./src/y2022/01.rs) | ⭐⭐ | `10.00µs` | `20.00µs` | `30.00µs` |
+| 2022 | [Day 2](./src/y2022/02.rs) |  | `1.00ms` | - | `1.00ms` |
+| 2022 | [Day 3](./src/y2022/03.rs) |  | - | - | - |
+
+**Total: 1.03ms**
+"
+        );
+    }
+
+    #[test]
+    fn test_path() {
+        assert!(path().is_absolute());
+        assert!(path().is_file());
+    }
+
+    #[test]
+    fn test_replace_region() {
+        let readme = format!("# Title\n{}\nold\n{}\nrest\n", MARKER, MARKER);
+
+        assert_eq!(
+            replace_region(&readme, "\nnew\n").unwrap(),
+            format!("# Title\n{}\nnew\n{}\nrest\n", MARKER, MARKER)
+        );
+        assert!(matches!(
+            replace_region("# Title\n", "new"),
+            Err(ReadmeError::MissingMarkers)
+        ));
+    }
+}
@@ -0,0 +1,293 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::error::{self, Error, ErrorKind};
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io::BufRead;
+use std::panic::{self, AssertUnwindSafe, UnwindSafe};
+use std::time::{Duration, Instant};
+
+/// A solver with its answer already rendered through `Display`.
+pub type Solver = fn(&str) -> Result<String, Error>;
+
+/// Like [`Solver`], but reads its input incrementally instead of taking all of it at once.
+pub type StreamSolver = fn(&mut dyn BufRead) -> Result<String, Error>;
+
+/// Renders the answer of a part function for a [`Solver`].
+pub fn answer<T: Display, E: Into<Error>>(result: Result<T, E>) -> Result<String, Error> {
+    result
+        .map(|answer| answer.to_string())
+        .map_err(|error| error.into())
+}
+
+fn part_one<S: crate::Solution>(input: &str) -> Result<String, Error> {
+    answer(S::part_one(input))
+}
+
+fn part_two<S: crate::Solution>(input: &str) -> Result<String, Error> {
+    answer(S::part_two(input))
+}
+
+/// The two parts of a day, as registered by the `solution!` macro.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+    /// The parts that can also read their input from a `BufRead`, part one first.
+    pub streaming: Option<[StreamSolver; 2]>,
+}
+
+impl Solution {
+    pub const fn of<S: crate::Solution>(year: u16, day: u8) -> Solution {
+        Solution {
+            year,
+            day,
+            part_one: part_one::<S>,
+            part_two: part_two::<S>,
+            streaming: None,
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => Some(self.part_one),
+            2 => Some(self.part_two),
+            _ => None,
+        }
+    }
+
+    pub fn stream_part(&self, part: u8) -> Option<StreamSolver> {
+        let [part_one, part_two] = self.streaming?;
+        match part {
+            1 => Some(part_one),
+            2 => Some(part_two),
+            _ => None,
+        }
+    }
+}
+
+/// What happened when a part was run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved {
+        answer: String,
+        elapsed: Duration,
+    },
+    /// The part returned an error, rendered with its source chain.
+    Errored {
+        kind: ErrorKind,
+        error: String,
+        elapsed: Duration,
+    },
+    Panicked {
+        message: String,
+    },
+    /// The part was not run because its input file does not exist.
+    MissingInput {
+        error: String,
+    },
+    NotImplemented,
+}
+
+/// Solutions callable in-process, keyed by year and day.
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), Solution>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    pub fn register(&mut self, solution: Solution) -> &mut Registry {
+        self.solutions
+            .insert((solution.year, solution.day), solution);
+        self
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Solution> {
+        self.solutions.get(&(year, day))
+    }
+
+    /// The years with at least one solution, in ascending order.
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.solutions.keys().map(|(year, _)| *year).collect();
+        years.dedup();
+        years
+    }
+
+    pub fn days(&self, year: u16) -> impl Iterator<Item = u8> + '_ {
+        self.solutions
+            .keys()
+            .filter(move |(solution_year, _)| *solution_year == year)
+            .map(|(_, day)| *day)
+    }
+
+    pub fn run(&self, year: u16, day: u8, part: u8, input: &str) -> Outcome {
+        match self.get(year, day).and_then(|solution| solution.part(part)) {
+            Some(solver) => run_solver(solver, input),
+            None => Outcome::NotImplemented,
+        }
+    }
+}
+
+/// Times a single call of `solver`, turning a panic into `Outcome::Panicked`.
+pub fn run_solver(solver: Solver, input: &str) -> Outcome {
+    let timer = Instant::now();
+    let result = catch_panic(|| solver(input));
+    let elapsed = timer.elapsed();
+
+    match result {
+        Ok(Ok(answer)) => Outcome::Solved { answer, elapsed },
+        Ok(Err(error)) => Outcome::Errored {
+            kind: error.kind(),
+            error: error::render(&error, input),
+            elapsed,
+        },
+        Err(message) => Outcome::Panicked { message },
+    }
+}
+
+/// Like [`run_solver`], timing the reads of the input as well. Parse errors are reported without a diagnostic,
+/// since the input is gone by then.
+pub fn run_stream_solver(solver: StreamSolver, reader: &mut dyn BufRead) -> Outcome {
+    let timer = Instant::now();
+    let result = catch_panic(AssertUnwindSafe(|| solver(reader)));
+    let elapsed = timer.elapsed();
+
+    match result {
+        Ok(Ok(answer)) => Outcome::Solved { answer, elapsed },
+        Ok(Err(error)) => Outcome::Errored {
+            kind: error.kind(),
+            error: error::report(&error),
+            elapsed,
+        },
+        Err(message) => Outcome::Panicked { message },
+    }
+}
+
+/// Runs `func`, returning the panic message if it panicked.
+pub fn catch_panic<T>(func: impl FnOnce() -> T + UnwindSafe) -> Result<T, String> {
+    panic::catch_unwind(func).map_err(|payload| panic_message(payload.as_ref()))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(input: &str) -> Result<String, Error> {
+        Ok(input.len().to_string())
+    }
+
+    fn error(_input: &str) -> Result<String, Error> {
+        Err("Not solved".into())
+    }
+
+    fn panics(_input: &str) -> Result<String, Error> {
+        panic!("boom")
+    }
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        registry.register(Solution {
+            year: 2022,
+            day: 1,
+            part_one: answer,
+            part_two: error,
+            streaming: None,
+        });
+        registry.register(Solution {
+            year: 2022,
+            day: 2,
+            part_one: panics,
+            part_two: answer,
+            streaming: None,
+        });
+        registry.register(Solution {
+            year: 2021,
+            day: 2,
+            part_one: error,
+            part_two: error,
+            streaming: None,
+        });
+        registry
+    }
+
+    #[test]
+    fn test_run_outcomes() {
+        let registry = registry();
+
+        assert!(matches!(
+            registry.run(2022, 1, 1, "abc"),
+            Outcome::Solved { answer, .. } if answer == "3"
+        ));
+        assert!(matches!(
+            registry.run(2022, 1, 2, "abc"),
+            Outcome::Errored { kind: ErrorKind::Logic, error, .. } if error == "Not solved"
+        ));
+        assert_eq!(
+            registry.run(2022, 2, 1, "abc"),
+            Outcome::Panicked {
+                message: "boom".to_string()
+            }
+        );
+        assert_eq!(registry.run(2022, 3, 1, "abc"), Outcome::NotImplemented);
+        assert_eq!(registry.run(2022, 1, 3, "abc"), Outcome::NotImplemented);
+        assert_eq!(registry.run(2021, 1, 1, "abc"), Outcome::NotImplemented);
+    }
+
+    struct Lengths;
+
+    impl crate::Solution for Lengths {
+        type AnswerOne = usize;
+        type AnswerTwo = u8;
+
+        fn part_one(input: &str) -> Result<usize, Error> {
+            Ok(input.len())
+        }
+
+        fn part_two(input: &str) -> Result<u8, Error> {
+            Ok(input.parse()?)
+        }
+    }
+
+    #[test]
+    fn test_solution_trait() {
+        const SOLUTION: Solution = Solution::of::<Lengths>(2020, 1);
+        let mut registry = Registry::new();
+        registry.register(SOLUTION);
+
+        assert!(matches!(
+            registry.run(2020, 1, 1, "abc"),
+            Outcome::Solved { answer, .. } if answer == "3"
+        ));
+        assert!(matches!(
+            registry.run(2020, 1, 2, "abc"),
+            Outcome::Errored { kind: ErrorKind::Parse, error, .. }
+                if error == "invalid number\ncaused by: invalid digit found in string"
+        ));
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(registry().years(), vec![2021, 2022]);
+        assert_eq!(registry().days(2022).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(registry().days(2021).collect::<Vec<_>>(), vec![2]);
+    }
+}
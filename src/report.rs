@@ -0,0 +1,353 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::{Answers, Verdict};
+use crate::bench::{Bench, Stats};
+use crate::registry::{self, Outcome, Solver, StreamSolver};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::fmt::Write;
+use std::io::BufRead;
+use std::time::Duration;
+
+/// How the runner prints results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable, ANSI-decorated output.
+    Text,
+    /// One JSON object per part and line.
+    Json,
+}
+
+impl Format {
+    /// `--json` switches to JSON Lines output, taken from the arguments of the runner.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Format {
+        if args.contains("--json") {
+            Format::Json
+        } else {
+            Format::Text
+        }
+    }
+}
+
+/// Everything known about a single part after running it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartReport {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub verdict: Verdict,
+    pub stats: Option<Stats>,
+}
+
+impl PartReport {
+    /// Runs `solver` once, then benchmarks it if requested and the part was solved.
+    pub fn run(
+        year: u16,
+        day: u8,
+        part: u8,
+        solver: Solver,
+        input: &str,
+        bench: Option<&Bench>,
+    ) -> PartReport {
+        let outcome = registry::run_solver(solver, input);
+        let stats = match (bench, &outcome) {
+            (Some(bench), Outcome::Solved { .. }) => Some(bench.run(solver, input)),
+            _ => None,
+        };
+
+        PartReport {
+            stats,
+            ..PartReport::from_outcome(year, day, part, outcome)
+        }
+    }
+
+    /// Runs `solver` once on `reader`. Streamed parts are not benchmarked, their input can only be read once.
+    pub fn run_stream(
+        year: u16,
+        day: u8,
+        part: u8,
+        solver: StreamSolver,
+        reader: &mut dyn BufRead,
+    ) -> PartReport {
+        let outcome = registry::run_stream_solver(solver, reader);
+        PartReport::from_outcome(year, day, part, outcome)
+    }
+
+    pub fn from_outcome(year: u16, day: u8, part: u8, outcome: Outcome) -> PartReport {
+        let verdict = Answers::load(year, day).verify(part, &outcome);
+        PartReport {
+            year,
+            day,
+            part,
+            outcome,
+            verdict,
+            stats: None,
+        }
+    }
+
+    /// Representative timing of a solved part, the median if it was benchmarked.
+    pub fn time(&self) -> Option<Duration> {
+        match (&self.outcome, &self.stats) {
+            (Outcome::Solved { .. }, Some(stats)) => Some(stats.median),
+            (Outcome::Solved { elapsed, .. }, None) => Some(*elapsed),
+            _ => None,
+        }
+    }
+
+    pub fn print(&self, format: Format) {
+        match format {
+            Format::Json => println!("{}", Record::new(self).to_json()),
+            Format::Text => self.print_text(),
+        }
+    }
+
+    fn print_text(&self) {
+        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET);
+        match &self.outcome {
+            Outcome::Solved { answer, elapsed } => {
+                let verdict = match self.verdict {
+                    Verdict::Unknown => "",
+                    Verdict::Correct => ", correct",
+                    Verdict::Wrong { .. } => ", wrong",
+                };
+                println!(
+                    "{} {}(elapsed: {:.2?}{}){}",
+                    answer, ANSI_ITALIC, elapsed, verdict, ANSI_RESET
+                );
+            }
+            Outcome::Errored { kind, error, .. } => {
+                println!("not solved ({} error).\n{}", kind.as_str(), error);
+            }
+            Outcome::Panicked { message } => {
+                println!("panicked.\n{}", message);
+            }
+            Outcome::MissingInput { error } => {
+                println!(
+                    "input missing, run `cargo download {} {}`.\n{}",
+                    self.year, self.day, error
+                );
+            }
+            Outcome::NotImplemented => {
+                println!("not implemented.");
+            }
+        }
+        if let Verdict::Wrong { expected } = &self.verdict {
+            println!("expected:\n{}", expected);
+        }
+        if let Some(stats) = &self.stats {
+            println!(
+                "{}(min: {:.2?}, median: {:.2?}, mean: {:.2?}, p95: {:.2?}, std dev: {:.2?}, runs: {}){}",
+                ANSI_ITALIC,
+                stats.min,
+                stats.median,
+                stats.mean,
+                stats.p95,
+                stats.std_dev,
+                stats.iterations,
+                ANSI_RESET
+            );
+        }
+    }
+}
+
+/// Machine-readable result of running a single part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: &'static str,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub error_kind: Option<&'static str>,
+    pub elapsed_ns: Option<u128>,
+    pub verdict: &'static str,
+    pub expected: Option<String>,
+    pub bench: Option<Stats>,
+}
+
+impl Record {
+    pub fn new(report: &PartReport) -> Record {
+        let (status, answer, error, elapsed) = match &report.outcome {
+            Outcome::Solved { answer, elapsed } => ("solved", Some(answer), None, Some(elapsed)),
+            Outcome::Errored { error, elapsed, .. } => {
+                ("errored", None, Some(error), Some(elapsed))
+            }
+            Outcome::Panicked { message } => ("panicked", None, Some(message), None),
+            Outcome::MissingInput { error } => ("missing_input", None, Some(error), None),
+            Outcome::NotImplemented => ("not_implemented", None, None, None),
+        };
+
+        Record {
+            year: report.year,
+            day: report.day,
+            part: report.part,
+            status,
+            answer: answer.cloned(),
+            error: error.cloned(),
+            error_kind: match &report.outcome {
+                Outcome::Errored { kind, .. } => Some(kind.as_str()),
+                _ => None,
+            },
+            elapsed_ns: elapsed.map(|elapsed| elapsed.as_nanos()),
+            verdict: report.verdict.as_str(),
+            expected: match &report.verdict {
+                Verdict::Wrong { expected } => Some(expected.clone()),
+                Verdict::Correct | Verdict::Unknown => None,
+            },
+            bench: report.stats,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"year\":{},\"day\":{},\"part\":{},\"status\":{},\"answer\":{},\"error\":{},\"error_kind\":{},\"elapsed_ns\":{},\"verdict\":{},\"expected\":{},\"bench\":{}}}",
+            self.year,
+            self.day,
+            self.part,
+            json_string(self.status),
+            json_option(self.answer.as_deref().map(json_string)),
+            json_option(self.error.as_deref().map(json_string)),
+            json_option(self.error_kind.map(json_string)),
+            json_option(self.elapsed_ns.map(|elapsed| elapsed.to_string())),
+            json_string(self.verdict),
+            json_option(self.expected.as_deref().map(json_string)),
+            json_option(self.bench.as_ref().map(stats_json)),
+        )
+    }
+}
+
+fn stats_json(stats: &Stats) -> String {
+    format!(
+        "{{\"iterations\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"p95_ns\":{},\"std_dev_ns\":{}}}",
+        stats.iterations,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.p95.as_nanos(),
+        stats.std_dev.as_nanos(),
+    )
+}
+
+fn json_option(value: Option<String>) -> String {
+    value.unwrap_or_else(|| "null".to_string())
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    fn record(outcome: Outcome, verdict: Verdict, stats: Option<Stats>) -> Record {
+        Record::new(&PartReport {
+            year: 2022,
+            day: 4,
+            part: 2,
+            outcome,
+            verdict,
+            stats,
+        })
+    }
+
+    #[test]
+    fn test_format_from_args() {
+        let parse = |args: &[&str]| {
+            let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+            (Format::from_args(&mut args), args.finish().len())
+        };
+
+        assert_eq!(parse(&["2022", "--json"]), (Format::Json, 1));
+        assert_eq!(parse(&["2022"]), (Format::Text, 1));
+    }
+
+    #[test]
+    fn test_record_to_json() {
+        let solved = Outcome::Solved {
+            answer: "24000".to_string(),
+            elapsed: Duration::from_nanos(74),
+        };
+        assert_eq!(
+            record(solved, Verdict::Correct, None).to_json(),
+            r#"{"year":2022,"day":4,"part":2,"status":"solved","answer":"24000","error":null,"error_kind":null,"elapsed_ns":74,"verdict":"correct","expected":null,"bench":null}"#
+        );
+
+        let errored = Outcome::Errored {
+            kind: ErrorKind::Parse,
+            error: "line 1:\n\t\"x\"".to_string(),
+            elapsed: Duration::from_micros(2),
+        };
+        let wrong = Verdict::Wrong {
+            expected: "4".to_string(),
+        };
+        assert_eq!(
+            record(errored, wrong, None).to_json(),
+            r#"{"year":2022,"day":4,"part":2,"status":"errored","answer":null,"error":"line 1:\n\t\"x\"","error_kind":"parse","elapsed_ns":2000,"verdict":"wrong","expected":"4","bench":null}"#
+        );
+
+        assert_eq!(
+            record(Outcome::NotImplemented, Verdict::Unknown, None).to_json(),
+            r#"{"year":2022,"day":4,"part":2,"status":"not_implemented","answer":null,"error":null,"error_kind":null,"elapsed_ns":null,"verdict":"unknown","expected":null,"bench":null}"#
+        );
+    }
+
+    #[test]
+    fn test_record_with_bench_to_json() {
+        let solved = Outcome::Solved {
+            answer: "1".to_string(),
+            elapsed: Duration::from_nanos(900),
+        };
+        let stats =
+            Stats::from_samples(&mut [Duration::from_nanos(100), Duration::from_nanos(300)]);
+        assert_eq!(
+            record(solved, Verdict::Unknown, Some(stats)).to_json(),
+            r#"{"year":2022,"day":4,"part":2,"status":"solved","answer":"1","error":null,"error_kind":null,"elapsed_ns":900,"verdict":"unknown","expected":null,"bench":{"iterations":2,"min_ns":100,"median_ns":200,"mean_ns":200,"p95_ns":300,"std_dev_ns":100}}"#
+        );
+    }
+
+    #[test]
+    fn test_time_prefers_median() {
+        let solved = Outcome::Solved {
+            answer: "1".to_string(),
+            elapsed: Duration::from_nanos(900),
+        };
+        let stats =
+            Stats::from_samples(&mut [Duration::from_nanos(100), Duration::from_nanos(300)]);
+        let mut report = PartReport {
+            year: 2022,
+            day: 1,
+            part: 1,
+            outcome: solved,
+            verdict: Verdict::Unknown,
+            stats: None,
+        };
+
+        assert_eq!(report.time(), Some(Duration::from_nanos(900)));
+        report.stats = Some(stats);
+        assert_eq!(report.time(), Some(Duration::from_nanos(200)));
+    }
+
+    #[test]
+    fn test_json_string_escapes_control_characters() {
+        assert_eq!(json_string("a\u{1b}[0m\\"), r#""a\u001b[0m\\""#);
+    }
+}
@@ -0,0 +1,299 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Write as _;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// The files `cargo scaffold` creates for a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Module,
+    Input,
+    Example,
+    Answers,
+}
+
+impl Kind {
+    pub const ALL: [Kind; 4] = [Kind::Module, Kind::Input, Kind::Example, Kind::Answers];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Kind::Module => "module",
+            Kind::Input => "input",
+            Kind::Example => "example",
+            Kind::Answers => "answers",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Missing,
+    /// Created by `cargo scaffold`, but not filled in yet.
+    Empty,
+    Present,
+}
+
+impl State {
+    fn of(path: &Path) -> State {
+        match fs::metadata(path) {
+            Ok(metadata) if metadata.len() > 0 => State::Present,
+            Ok(_) => State::Empty,
+            Err(_) => State::Missing,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayFile {
+    pub kind: Kind,
+    pub path: PathBuf,
+    pub state: State,
+}
+
+/// The files of a day below `src`, e.g. `src/y2022/07.rs` and `src/inputs/2022/07.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStatus {
+    pub year: u16,
+    pub day: u8,
+    pub files: Vec<DayFile>,
+}
+
+impl DayStatus {
+    pub fn scan(src: &Path, year: u16, day: u8) -> DayStatus {
+        let data_file = |folder: &str| {
+            src.join(folder)
+                .join(year.to_string())
+                .join(format!("{:02}.txt", day))
+        };
+        let files = Kind::ALL
+            .iter()
+            .map(|&kind| {
+                let path = match kind {
+                    Kind::Module => src
+                        .join(format!("y{}", year))
+                        .join(format!("{:02}.rs", day)),
+                    Kind::Input => data_file("inputs"),
+                    Kind::Example => return example_file(src, year, day),
+                    Kind::Answers => data_file("answers"),
+                };
+                DayFile {
+                    kind,
+                    state: State::of(&path),
+                    path,
+                }
+            })
+            .collect();
+
+        DayStatus { year, day, files }
+    }
+
+    pub fn get(&self, kind: Kind) -> &DayFile {
+        // every kind is scanned, in the order of `Kind::ALL`.
+        &self.files[Kind::ALL.iter().position(|&k| k == kind).unwrap()]
+    }
+
+    pub fn missing(&self) -> impl Iterator<Item = &DayFile> {
+        self.files
+            .iter()
+            .filter(|file| file.state == State::Missing)
+    }
+
+    /// Whether any file of the day exists.
+    pub fn is_started(&self) -> bool {
+        self.files.iter().any(|file| file.state != State::Missing)
+    }
+
+    /// Whether every file of the day exists and has content, which includes a recorded answer.
+    pub fn is_complete(&self) -> bool {
+        self.files.iter().all(|file| file.state == State::Present)
+    }
+}
+
+/// The years with a `yYYYY` module folder in `src`, in order.
+pub fn years(src: &Path) -> Vec<u16> {
+    let mut years: Vec<u16> = fs::read_dir(src)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let name = entry.ok()?.file_name().into_string().ok()?;
+                    name.strip_prefix('y')?.parse().ok()
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    years.sort_unstable();
+    years
+}
+
+/// The examples folder `NN/` with an `a.txt`, or the single `NN.txt` that older days use.
+fn example_file(src: &Path, year: u16, day: u8) -> DayFile {
+    let examples_dir = src.join("examples").join(year.to_string());
+    let dir = examples_dir.join(format!("{:02}", day));
+    let legacy = examples_dir.join(format!("{:02}.txt", day));
+
+    let examples: Vec<PathBuf> = fs::read_dir(&dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let (path, state) = if examples
+        .iter()
+        .any(|path| State::of(path) == State::Present)
+    {
+        (dir, State::Present)
+    } else if State::of(&legacy) != State::Missing {
+        (legacy.clone(), State::of(&legacy))
+    } else if !examples.is_empty() {
+        (dir, State::Empty)
+    } else {
+        (dir.join("a.txt"), State::Missing)
+    };
+
+    DayFile {
+        kind: Kind::Example,
+        path,
+        state,
+    }
+}
+
+/// Creates a missing file, with `content` for the module and empty otherwise.
+///
+/// Never touches an existing file, even if it appeared since the day was scanned.
+pub fn create(file: &DayFile, content: &str) -> io::Result<()> {
+    if let Some(parent) = file.path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut created = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&file.path)?;
+    if file.kind == Kind::Module {
+        created.write_all(content.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Renders a table with a row per day, marking empty and missing files.
+pub fn render_table(statuses: &[DayStatus]) -> String {
+    let mut table = String::new();
+    let header: Vec<&str> = Kind::ALL.iter().map(|kind| kind.name()).collect();
+    write_row(&mut table, "year day", &header, "complete");
+
+    for status in statuses {
+        let cells: Vec<&str> = status
+            .files
+            .iter()
+            .map(|file| match file.state {
+                State::Present => "✓",
+                State::Empty => "empty",
+                State::Missing => "missing",
+            })
+            .collect();
+        let complete = if status.is_complete() { "✓" } else { "" };
+        let label = format!("{} {:02}", status.year, status.day);
+        write_row(&mut table, &label, &cells, complete);
+    }
+
+    table
+}
+
+fn write_row(table: &mut String, day: &str, cells: &[&str], complete: &str) {
+    let mut row = format!("{:<10}", day);
+    for cell in cells {
+        write!(row, "{:<9}", cell).unwrap();
+    }
+    row.push_str(complete);
+    writeln!(table, "{}", row.trim_end()).unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temp_src(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_scaffold_{}_{}", name, std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        dir
+    }
+
+    fn states(status: &DayStatus) -> Vec<State> {
+        status.files.iter().map(|file| file.state).collect()
+    }
+
+    #[test]
+    fn test_scan_and_repair() {
+        let src = temp_src("repair");
+        let status = DayStatus::scan(&src, 2022, 7);
+        assert!(!status.is_started());
+        assert_eq!(status.missing().count(), 4);
+        assert_eq!(
+            status.get(Kind::Example).path,
+            src.join("examples/2022/07/a.txt")
+        );
+
+        fs::create_dir_all(src.join("y2022")).unwrap();
+        fs::write(src.join("y2022/07.rs"), "// solved").unwrap();
+        let status = DayStatus::scan(&src, 2022, 7);
+        for file in status.missing() {
+            create(file, "// template").unwrap();
+        }
+
+        let status = DayStatus::scan(&src, 2022, 7);
+        assert_eq!(
+            states(&status),
+            vec![State::Present, State::Empty, State::Empty, State::Empty]
+        );
+        assert_eq!(
+            fs::read_to_string(src.join("y2022/07.rs")).unwrap(),
+            "// solved"
+        );
+        assert!(create(status.get(Kind::Input), "").is_err());
+
+        fs::write(src.join("inputs/2022/07.txt"), "1\n").unwrap();
+        fs::write(src.join("examples/2022/07/b.txt"), "1\n").unwrap();
+        fs::write(src.join("answers/2022/07.txt"), "--- part 1 ---\n1\n").unwrap();
+        assert!(DayStatus::scan(&src, 2022, 7).is_complete());
+
+        assert_eq!(years(&src), vec![2022]);
+        fs::remove_dir_all(src).unwrap();
+    }
+
+    #[test]
+    fn test_scan_legacy_example() {
+        let src = temp_src("legacy");
+        fs::create_dir_all(src.join("examples/2022")).unwrap();
+        fs::write(src.join("examples/2022/05.txt"), "move 1 from 2 to 1\n").unwrap();
+
+        let example = DayStatus::scan(&src, 2022, 5).get(Kind::Example).clone();
+        assert_eq!(example.state, State::Present);
+        assert_eq!(example.path, src.join("examples/2022/05.txt"));
+
+        fs::remove_dir_all(src).unwrap();
+    }
+
+    #[test]
+    fn test_render_table() {
+        let src = temp_src("table");
+        fs::create_dir_all(src.join("y2022")).unwrap();
+        fs::write(src.join("y2022/03.rs"), "// solved").unwrap();
+        fs::create_dir_all(src.join("inputs/2022")).unwrap();
+        fs::write(src.join("inputs/2022/03.txt"), "").unwrap();
+
+        assert_eq!(
+            render_table(&[DayStatus::scan(&src, 2022, 3)]),
+            "year day  module   input    example  answers  complete\n\
+             2022 03   ✓        empty    missing  missing\n"
+        );
+
+        fs::remove_dir_all(src).unwrap();
+    }
+}
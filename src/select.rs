@@ -0,0 +1,187 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeSet;
+
+/// The first Advent of Code took place in 2015.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Parses a day selection such as `5`, `3..=7`, `3..8` or `1,5,9` into sorted, unique days.
+pub fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let mut days = BTreeSet::new();
+
+    for item in spec.split(',').map(str::trim) {
+        let range = if let Some((start, end)) = item.split_once("..=") {
+            parse_day(start)?..=parse_day(end)?
+        } else if let Some((start, end)) = item.split_once("..") {
+            let end = parse_day(end)?;
+            if end == 1 {
+                return Err(format!("`{}` does not contain any day", item));
+            }
+            parse_day(start)?..=end - 1
+        } else {
+            let day = parse_day(item)?;
+            day..=day
+        };
+
+        if range.is_empty() {
+            return Err(format!("`{}` does not contain any day", item));
+        }
+        days.extend(range);
+    }
+
+    Ok(days.into_iter().collect())
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.trim().parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("`{}` is not a day between 1 and 25", value)),
+    }
+}
+
+pub fn parse_part(value: &str) -> Result<u8, String> {
+    match value.trim().parse::<u8>() {
+        Ok(part) if part == 1 || part == 2 => Ok(part),
+        _ => Err(format!("`{}` is not a part, expected 1 or 2", value)),
+    }
+}
+
+pub fn parse_year(value: &str) -> Result<u16, String> {
+    match value.trim().parse::<u16>() {
+        Ok(year) if (FIRST_YEAR..=9999).contains(&year) => Ok(year),
+        _ => Err(format!("`{}` is not a year since {}", value, FIRST_YEAR)),
+    }
+}
+
+/// Whether a free argument is meant as a year rather than a day selection, like `2022` in `2022 5`.
+pub fn is_year(value: &str) -> bool {
+    value.len() == 4 && value.chars().all(|c| c.is_ascii_digit())
+}
+
+fn invalid(value: &str, cause: String) -> pico_args::Error {
+    pico_args::Error::Utf8ArgumentParsingFailed {
+        value: value.to_string(),
+        cause,
+    }
+}
+
+/// Reads `<day>` or `<year> <day>` from the free arguments, the year is `None` if it was left out.
+pub fn year_and_day(
+    args: &mut pico_args::Arguments,
+) -> Result<(Option<u16>, u8), pico_args::Error> {
+    let first: String = args.free_from_str()?;
+    let second: Option<String> = args.opt_free_from_str()?;
+
+    let (year, day) = match &second {
+        Some(day) => (
+            Some(parse_year(&first).map_err(|cause| invalid(&first, cause))?),
+            day,
+        ),
+        None => (None, &first),
+    };
+    let day = parse_day(day).map_err(|cause| invalid(day, cause))?;
+    Ok((year, day))
+}
+
+/// Reads an optional year followed by an optional day selection, as in `2022 3..=7`, `2022` or `3..=7`.
+pub fn year_and_days(
+    args: &mut pico_args::Arguments,
+) -> Result<(Option<u16>, Option<Vec<u8>>), pico_args::Error> {
+    let first: Option<String> = args.opt_free_from_str()?;
+    let (year, days) = match first {
+        Some(first) if is_year(&first) => {
+            let year = parse_year(&first).map_err(|cause| invalid(&first, cause))?;
+            (Some(year), args.opt_free_from_str::<String>()?)
+        }
+        first => (None, first),
+    };
+
+    let days = match days {
+        Some(spec) => Some(parse_days(&spec).map_err(|cause| invalid(&spec, cause))?),
+        None => None,
+    };
+    Ok((year, days))
+}
+
+/// Whether `part` should run when only `selected` was asked for.
+pub fn includes_part(selected: Option<u8>, part: u8) -> bool {
+    selected.is_none_or(|selected| selected == part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("5"), Ok(vec![5]));
+        assert_eq!(parse_days("3..=7"), Ok(vec![3, 4, 5, 6, 7]));
+        assert_eq!(parse_days("3..6"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days("9,1,5"), Ok(vec![1, 5, 9]));
+        assert_eq!(parse_days("1..=3,2,24..=25"), Ok(vec![1, 2, 3, 24, 25]));
+    }
+
+    #[test]
+    fn test_parse_days_rejects_invalid() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("7..=3").is_err());
+        assert!(parse_days("3..3").is_err());
+        assert!(parse_days("1..1").is_err());
+        assert!(parse_days("1,,2").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn test_years() {
+        assert_eq!(parse_year("2022"), Ok(2022));
+        assert!(parse_year("2014").is_err());
+        assert!(parse_year("22").is_err());
+        assert!(is_year("2021"));
+        assert!(!is_year("5"));
+        assert!(!is_year("1..=25"));
+    }
+
+    #[test]
+    fn test_year_and_day() {
+        let parse = |args: &[&str]| {
+            let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+            year_and_day(&mut args).map_err(|e| e.to_string())
+        };
+
+        assert_eq!(parse(&["5"]), Ok((None, 5)));
+        assert_eq!(parse(&["2021", "05"]), Ok((Some(2021), 5)));
+        assert!(parse(&["26"]).is_err());
+        assert!(parse(&["5", "2021"]).is_err());
+        assert!(parse(&[]).is_err());
+    }
+
+    #[test]
+    fn test_year_and_days() {
+        let parse = |args: &[&str]| {
+            let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+            year_and_days(&mut args).map_err(|e| e.to_string())
+        };
+
+        assert_eq!(parse(&[]), Ok((None, None)));
+        assert_eq!(parse(&["2021"]), Ok((Some(2021), None)));
+        assert_eq!(
+            parse(&["2021", "1..=2"]),
+            Ok((Some(2021), Some(vec![1, 2])))
+        );
+        assert_eq!(parse(&["1..=2"]), Ok((None, Some(vec![1, 2]))));
+        assert!(parse(&["1999"]).is_err());
+        assert!(parse(&["2022", "26"]).is_err());
+    }
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(parse_part("2"), Ok(2));
+        assert!(parse_part("3").is_err());
+        assert!(includes_part(None, 1));
+        assert!(includes_part(Some(2), 2));
+        assert!(!includes_part(Some(2), 1));
+    }
+}
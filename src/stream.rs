@@ -0,0 +1,257 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::Error;
+use std::fmt::Debug;
+use std::io::{self, BufRead, BufReader, Bytes, Read};
+
+/// The lines of `reader` without their line endings, like `str::lines`, read one at a time.
+pub fn lines<R: BufRead>(reader: R) -> impl Iterator<Item = Result<String, Error>> {
+    reader.lines().map(|line| line.map_err(Error::from))
+}
+
+/// Like [`lines`], but an empty input is read as a single empty line.
+///
+/// Whole-input parsers of one or more lines, like [`crate::helpers::parse::lines_of`], reject an empty input. A day
+/// that parses each line on its own does so too when it reads the lines from here.
+pub fn lines1<R: BufRead>(reader: R) -> impl Iterator<Item = Result<String, Error>> {
+    let mut lines = lines(reader).peekable();
+    let empty = lines.peek().is_none();
+    empty.then(|| Ok(String::new())).into_iter().chain(lines)
+}
+
+/// The characters of `reader`, like `str::chars`, decoded one at a time.
+pub fn chars<R: BufRead>(reader: R) -> Chars<R> {
+    Chars {
+        bytes: reader.bytes(),
+    }
+}
+
+pub struct Chars<R> {
+    bytes: Bytes<R>,
+}
+
+impl<R: Read> Iterator for Chars<R> {
+    type Item = Result<char, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.bytes.next()? {
+            Ok(byte) => byte,
+            Err(e) => return Some(Err(e.into())),
+        };
+        let width = match first {
+            0x00..=0x7f => 1,
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            0xf0..=0xf7 => 4,
+            _ => return Some(Err(invalid_utf8())),
+        };
+
+        let mut encoded = [first, 0, 0, 0];
+        for byte in &mut encoded[1..width] {
+            *byte = match self.bytes.next() {
+                Some(Ok(byte)) => byte,
+                Some(Err(e)) => return Some(Err(e.into())),
+                None => return Some(Err(invalid_utf8())),
+            };
+        }
+        Some(
+            std::str::from_utf8(&encoded[..width])
+                .ok()
+                .and_then(|decoded| decoded.chars().next())
+                .ok_or_else(invalid_utf8),
+        )
+    }
+}
+
+fn invalid_utf8() -> Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "stream did not contain valid UTF-8",
+    )
+    .into()
+}
+
+/// A small xorshift generator for the inputs of property tests, see [`check`].
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // xorshift never leaves zero, any other seed works.
+        Rng(seed.max(1))
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: std::ops::Range<u64>) -> u64 {
+        range.start + self.next_u64() % (range.end - range.start)
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len() as u64) as usize]
+    }
+
+    /// `true` once in `n` draws on average, to break a generated line now and then.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.range(0..n) == 0
+    }
+}
+
+/// An input of fewer than `max_lines` lines, each made by `line` and terminated by a newline.
+///
+/// `line` should now and then produce a malformed line, so that [`check`] also sees both entry points reject it.
+pub fn generate_lines(
+    rng: &mut Rng,
+    max_lines: u64,
+    mut line: impl FnMut(&mut Rng) -> String,
+) -> String {
+    let mut input = String::new();
+    for _ in 0..rng.range(0..max_lines) {
+        input.push_str(&line(rng));
+        input.push('\n');
+    }
+    input
+}
+
+/// How many inputs [`check`] generates.
+pub const CASES: u64 = 64;
+
+/// Checks that a streaming entry point agrees with the `&str` one on generated inputs.
+///
+/// Both must return the same answer, or both fail. The reader has a tiny buffer, so lines and characters are split
+/// across reads. The empty input is always checked as well, whatever `generate` produces.
+pub fn check<T: PartialEq + Debug>(
+    generate: impl Fn(&mut Rng) -> String,
+    from_str: impl Fn(&str) -> Result<T, Error>,
+    from_reader: impl Fn(&mut dyn BufRead) -> Result<T, Error>,
+) {
+    for seed in 0..=CASES {
+        let input = match seed {
+            0 => String::new(),
+            _ => generate(&mut Rng::new(seed)),
+        };
+        let expected = from_str(&input);
+        let streamed = from_reader(&mut BufReader::with_capacity(3, input.as_bytes()));
+        match (&expected, &streamed) {
+            (Ok(expected), Ok(streamed)) => assert_eq!(
+                expected, streamed,
+                "different answers for seed {}, input:\n{}",
+                seed, input
+            ),
+            (Err(_), Err(_)) => {}
+            _ => panic!(
+                "only one of {:?} and {:?} failed for seed {}, input:\n{}",
+                expected, streamed, seed, input
+            ),
+        }
+    }
+}
+
+/// Generates property tests that the streaming entry points of a day agree with its part functions, see [`check`].
+///
+/// The inputs come from `generate(&mut Rng)`, or for inputs of independent lines, from
+/// `lines(max_lines, line)` with `line(&mut Rng)` as in [`generate_lines`].
+#[macro_export]
+macro_rules! stream_tests {
+    (lines($max_lines:expr, $line:expr), $part_one:ident, $part_one_stream:ident, $part_two:ident, $part_two_stream:ident) => {
+        advent_of_code::stream_tests!(
+            |rng: &mut advent_of_code::stream::Rng| {
+                advent_of_code::stream::generate_lines(rng, $max_lines, $line)
+            },
+            $part_one,
+            $part_one_stream,
+            $part_two,
+            $part_two_stream
+        );
+    };
+    ($generate:expr, $part_one:ident, $part_one_stream:ident, $part_two:ident, $part_two_stream:ident) => {
+        #[test]
+        fn test_stream_part_one() {
+            advent_of_code::stream::check(
+                $generate,
+                |input| $part_one(input),
+                |reader| $part_one_stream(reader),
+            );
+        }
+
+        #[test]
+        fn test_stream_part_two() {
+            advent_of_code::stream::check(
+                $generate,
+                |input| $part_two(input),
+                |reader| $part_two_stream(reader),
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let input = "a\r\nbc\n\nd";
+        let streamed: Vec<String> = lines(BufReader::with_capacity(1, input.as_bytes()))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(streamed, input.lines().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_lines1() {
+        let read = |input: &str| {
+            lines1(input.as_bytes())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()
+        };
+        assert_eq!(read(""), vec![""]);
+        assert_eq!(read("\n"), vec![""]);
+        assert_eq!(read("a\nb"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_chars() {
+        let input = "aé€😀\n";
+        let streamed: String = chars(BufReader::with_capacity(1, input.as_bytes()))
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(streamed, input);
+
+        let invalid: &[u8] = &[b'a', 0xe2, 0x82];
+        let mut decoded = chars(invalid);
+        assert!(matches!(decoded.next(), Some(Ok('a'))));
+        assert_eq!(
+            decoded.next().map(|c| c.map_err(|e| e.kind())),
+            Some(Err(crate::ErrorKind::Input))
+        );
+    }
+
+    #[test]
+    fn test_generate_lines() {
+        for seed in 1..=CASES {
+            let input = generate_lines(&mut Rng::new(seed), 5, |rng| {
+                "x".repeat(rng.range(0..3) as usize)
+            });
+            assert!(input.lines().count() < 5);
+            assert!(input.is_empty() || input.ends_with('\n'));
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let generate = |rng: &mut Rng| "x".repeat(rng.range(0..10) as usize);
+        check(
+            generate,
+            |input| Ok(input.len()),
+            |reader| Ok(lines(reader).map(|line| line.unwrap().len()).sum()),
+        );
+    }
+}
@@ -0,0 +1,187 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::{Path, PathBuf};
+use std::{fmt, fs, io};
+
+/// Where `cargo scaffold` looks for templates, `.templates/<name>.rs`.
+pub const DIR: &str = ".templates";
+
+/// The template folder of this crate, independent of the working directory.
+pub fn dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(DIR)
+}
+pub const DEFAULT: &str = "default";
+pub const DEFAULT_ANSWER_TYPE: &str = "u32";
+
+/// The templates that ship with the repository, used when their file was removed.
+const BUILTIN: [(&str, &str); 2] = [
+    ("default", include_str!("../.templates/default.rs")),
+    ("nom", include_str!("../.templates/nom.rs")),
+];
+
+#[derive(Debug)]
+pub enum TemplateError {
+    Unknown { name: String, path: PathBuf },
+    Io(io::Error),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::Unknown { name, path } => write!(
+                f,
+                "unknown template \"{}\", create \"{}\" or use one of: {}",
+                name,
+                path.display(),
+                BUILTIN.map(|(name, _)| name).join(", ")
+            ),
+            TemplateError::Io(e) => write!(f, "could not read template: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+impl From<io::Error> for TemplateError {
+    fn from(e: io::Error) -> Self {
+        TemplateError::Io(e)
+    }
+}
+
+/// The values of the placeholders of a template.
+///
+/// | Placeholder       | Value                                   |
+/// | ----------------- | --------------------------------------- |
+/// | `{{year}}`        | `2022`                                  |
+/// | `{{day}}`         | `7`                                     |
+/// | `{{day_padded}}`  | `07`                                    |
+/// | `{{title}}`       | `No Space Left On Device`               |
+/// | `{{answer_type}}` | `u32`, the `Ok` type of both parts      |
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholders {
+    pub year: u16,
+    pub day: u8,
+    pub title: String,
+    pub answer_type: String,
+}
+
+impl Placeholders {
+    fn value(&self, name: &str) -> Option<String> {
+        match name {
+            "year" => Some(self.year.to_string()),
+            "day" => Some(self.day.to_string()),
+            "day_padded" => Some(format!("{:02}", self.day)),
+            "title" => Some(self.title.clone()),
+            "answer_type" => Some(self.answer_type.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the template `name` from `dir`, falling back to the built-in template of that name.
+pub fn load(dir: &Path, name: &str) -> Result<String, TemplateError> {
+    let path = dir.join(format!("{}.rs", name));
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => BUILTIN
+            .iter()
+            .find(|(builtin, _)| *builtin == name)
+            .map(|(_, template)| template.to_string())
+            .ok_or(TemplateError::Unknown {
+                name: name.to_string(),
+                path,
+            }),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Replaces every `{{name}}` of a known placeholder. Anything else, like `{{}}` in a format string, is kept.
+pub fn render(template: &str, placeholders: &Placeholders) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let value = rest
+            .find("}}")
+            .and_then(|end| Some((end, placeholders.value(&rest[2..end])?)));
+        match value {
+            Some((end, value)) => {
+                rendered.push_str(&value);
+                rest = &rest[end + 2..];
+            }
+            None => {
+                rendered.push_str("{{");
+                rest = &rest[2..];
+            }
+        }
+    }
+
+    rendered.push_str(rest);
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn placeholders() -> Placeholders {
+        Placeholders {
+            year: 2022,
+            day: 7,
+            title: "No Space Left On Device".to_string(),
+            answer_type: "u64".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(
+                "//! Day {{day}} of {{year}}: {{title}}\nfn part_one() -> Result<{{answer_type}}, Error> {}\n// {{day_padded}}.txt",
+                &placeholders()
+            ),
+            "//! Day 7 of 2022: No Space Left On Device\nfn part_one() -> Result<u64, Error> {}\n// 07.txt"
+        );
+        assert_eq!(
+            render("format!(\"{{}} {{name}}\", {{day}})", &placeholders()),
+            "format!(\"{{}} {{name}}\", 7)"
+        );
+        assert_eq!(render("{{day", &placeholders()), "{{day");
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = env::temp_dir().join(format!("aoc_templates_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("custom.rs"), "// {{day}}").unwrap();
+
+        assert_eq!(load(&dir, "custom").unwrap(), "// {{day}}");
+        assert!(load(&dir, "nom").unwrap().contains("mod parser"));
+        assert!(matches!(
+            load(&dir, "missing"),
+            Err(TemplateError::Unknown { .. })
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_builtin_templates_use_known_placeholders() {
+        for (name, template) in BUILTIN {
+            let rendered = render(template, &placeholders());
+            assert!(
+                !rendered.contains("{{"),
+                "template {} has an unknown placeholder",
+                name
+            );
+            assert!(rendered.contains("advent_of_code::solution!(2022, 7, part_one, part_two);"));
+            assert!(rendered.contains("Result<u64, Error>"));
+        }
+    }
+}
@@ -1,4 +1,7 @@
+use advent_of_code::{stream, Error};
 use itertools::{FoldWhile, Itertools};
+use std::fmt;
+use std::io::BufRead;
 use std::str::FromStr;
 
 type Round = BaseRound<OutcomeDirection>;
@@ -96,7 +99,7 @@ where
         }
 
         let opponent_move: Result<OpponentMove, GameMoveParsingError> = result
-            .get(0)
+            .first()
             .expect("we checked indexes above")
             .parse::<OpponentMove>();
         let player_move: Result<T, GameMoveParsingError> = result
@@ -130,8 +133,8 @@ impl FromStr for OpponentMove {
     }
 }
 
-impl ToString for GameMoveParsingError {
-    fn to_string(&self) -> String {
+impl fmt::Display for GameMoveParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::UnknownMove {
                 supplied_play,
@@ -139,14 +142,16 @@ impl ToString for GameMoveParsingError {
             } => {
                 let player_or_opponent_str = if !*is_player { "player" } else { "opponent" };
                 let expected: &str = if !*is_player { "X, Y, Z" } else { "A, B, C" };
-                format!(
+                write!(
+                    f,
                     "supplied game move [{}] for [{}] expected one in [{}] as [Rock, Paper, Scissor]",
                     supplied_play, player_or_opponent_str, expected
                 )
             }
             Self::UnknowOutcomeDirection { supplied_direction } => {
                 let expected: &str = "X, Y, Z";
-                format!(
+                write!(
+                    f,
                     "supplied direction [{}] expected one in [{}] as [OpponentWins, Draw, PlayerWins]",
                     supplied_direction, expected
                 )
@@ -155,45 +160,53 @@ impl ToString for GameMoveParsingError {
     }
 }
 
-impl ToString for RoundParsingError {
-    fn to_string(&self) -> String {
+impl fmt::Display for RoundParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             RoundParsingError::LessMovesThanPlayers { supplied_moves } => {
-                format!(
+                write!(
+                    f,
                     "Less moves than players have been supplied\n\tsupplied: {}",
                     &supplied_moves.iter().join(",")
                 )
             }
             RoundParsingError::MoreMovesThanPlayers { supplied_moves } => {
-                format!(
+                write!(
+                    f,
                     "More moves than players have been supplied\n\tsupplied: {}",
                     &supplied_moves.iter().join(",")
                 )
             }
             RoundParsingError::UnknownPlayerMove(e) => {
-                format!(
+                write!(
+                    f,
                     "An invalid move or direction has been supplied for:\n\tPlayer:{}",
-                    e.to_string()
+                    e
                 )
             }
             RoundParsingError::UnknownOpponentMove(e) => {
-                format!(
+                write!(
+                    f,
                     "An invalid move or direction has been supplied for:\n\tOpponent:{}",
-                    e.to_string()
+                    e
                 )
             }
 
             RoundParsingError::UnknownMoves(opponent_err, player_err) => {
-                format!(
+                write!(
+                    f,
                     "Invalid moves or directions supplied for all the players:\n\tOpponent:{}\n\tPlayer:{}",
-                    opponent_err.to_string(),
-                    player_err.to_string()
+                    opponent_err, player_err
                 )
             }
         }
     }
 }
 
+impl std::error::Error for GameMoveParsingError {}
+
+impl std::error::Error for RoundParsingError {}
+
 impl FromStr for MisinterpretedPlayerMove {
     type Err = GameMoveParsingError;
 
@@ -288,13 +301,20 @@ fn evaluate_outcome(outcome: RoundOutcome) -> u32 {
         RoundOutcome::Draw(m) => evaluate_move(m.0) + 3,
     }
 }
-fn solve<T>(input: &str) -> Result<u32, String>
+fn solve<T, L>(lines: impl Iterator<Item = L>) -> Result<u32, Error>
 where
     T: Solvable + FromStr<Err = RoundParsingError>,
+    L: AsRef<str>,
 {
-    input
-        .lines()
-        .map(|s| s.parse::<T>())
+    lines
+        .enumerate()
+        .map(|(index, s)| {
+            s.as_ref().parse::<T>().map_err(|e| {
+                Error::parse("invalid round")
+                    .at_line(index + 1)
+                    .with_source(e)
+            })
+        })
         .fold_while(Ok(0), |acc, elem| match elem {
             Ok(round) => {
                 let outcome = Solvable::solve(&round);
@@ -305,36 +325,76 @@ where
             Err(error) => FoldWhile::Done(Err(error)),
         })
         .into_inner()
-        .map_err(|e| e.to_string())
 }
 
-pub fn part_one(input: &str) -> Result<u32, String> {
-    solve::<MisinterpretedRound>(input)
+/// Like `solve`, reading one line at a time.
+fn solve_stream<T>(reader: impl BufRead) -> Result<u32, Error>
+where
+    T: Solvable + FromStr<Err = RoundParsingError>,
+{
+    itertools::process_results(stream::lines(reader), |lines| solve::<T, _>(lines))?
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    solve::<MisinterpretedRound, _>(input.lines())
 }
 
-pub fn part_two(input: &str) -> Result<u32, String> {
-    solve::<Round>(input)
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    solve::<Round, _>(input.lines())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub fn part_one_stream(reader: impl BufRead) -> Result<u32, Error> {
+    solve_stream::<MisinterpretedRound>(reader)
 }
 
+pub fn part_two_stream(reader: impl BufRead) -> Result<u32, Error> {
+    solve_stream::<Round>(reader)
+}
+
+advent_of_code::solution!(
+    2022,
+    2,
+    part_one,
+    part_two,
+    streaming: part_one_stream,
+    part_two_stream
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::error::report;
+
+    fn rendered(result: Result<u32, Error>) -> Result<u32, String> {
+        result.map_err(|e| report(&e))
+    }
+
+    fn round(rng: &mut advent_of_code::stream::Rng) -> String {
+        let opponent = rng.pick(&["A", "B", "C"]);
+        let player = match rng.one_in(200) {
+            true => "D",
+            false => rng.pick(&["X", "Y", "Z"]),
+        };
+        format!("{} {}", opponent, player)
+    }
+
+    advent_of_code::stream_tests!(
+        lines(50, round),
+        part_one,
+        part_one_stream,
+        part_two,
+        part_two_stream
+    );
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", 2022, 2);
         assert_eq!(part_one(&input), Ok(15));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", 2022, 2);
         assert_eq!(part_two(&input), Ok(12));
     }
 
@@ -351,12 +411,12 @@ mod tests {
         let input = "A X\nB ";
 
         assert_eq!(
-            part_one(input),
-            Err("Less moves than players have been supplied\n\tsupplied: B".to_string())
+            rendered(part_one(input)),
+            Err("line 2: invalid round\ncaused by: Less moves than players have been supplied\n\tsupplied: B".to_string())
         );
         assert_eq!(
-            part_two(input),
-            Err("Less moves than players have been supplied\n\tsupplied: B".to_string())
+            rendered(part_two(input)),
+            Err("line 2: invalid round\ncaused by: Less moves than players have been supplied\n\tsupplied: B".to_string())
         );
     }
 
@@ -365,12 +425,12 @@ mod tests {
         let input = "A X\nB X A ";
 
         assert_eq!(
-            part_one(input),
-            Err("More moves than players have been supplied\n\tsupplied: B,X,A".to_string())
+            rendered(part_one(input)),
+            Err("line 2: invalid round\ncaused by: More moves than players have been supplied\n\tsupplied: B,X,A".to_string())
         );
         assert_eq!(
-            part_two(input),
-            Err("More moves than players have been supplied\n\tsupplied: B,X,A".to_string())
+            rendered(part_two(input)),
+            Err("line 2: invalid round\ncaused by: More moves than players have been supplied\n\tsupplied: B,X,A".to_string())
         );
     }
 
@@ -378,14 +438,14 @@ mod tests {
     fn test_wrong_moves_opponent() {
         let input = "A X\nZ X";
 
-        assert_eq!(part_one(input), Err("An invalid move or direction has been supplied for:\n\tOpponent:supplied game move [Z] for [opponent] expected one in [A, B, C] as [Rock, Paper, Scissor]".to_string()));
-        assert_eq!(part_two(input), Err("An invalid move or direction has been supplied for:\n\tOpponent:supplied game move [Z] for [opponent] expected one in [A, B, C] as [Rock, Paper, Scissor]".to_string()));
+        assert_eq!(rendered(part_one(input)), Err("line 2: invalid round\ncaused by: An invalid move or direction has been supplied for:\n\tOpponent:supplied game move [Z] for [opponent] expected one in [A, B, C] as [Rock, Paper, Scissor]".to_string()));
+        assert_eq!(rendered(part_two(input)), Err("line 2: invalid round\ncaused by: An invalid move or direction has been supplied for:\n\tOpponent:supplied game move [Z] for [opponent] expected one in [A, B, C] as [Rock, Paper, Scissor]".to_string()));
     }
     #[test]
     fn test_wrong_moves_player() {
         let input = "A X\nB A";
 
-        assert_eq!(part_one(input), Err("An invalid move or direction has been supplied for:\n\tPlayer:supplied game move [A] for [player] expected one in [X, Y, Z] as [Rock, Paper, Scissor]".to_string()));
-        assert_eq!(part_two(input), Err("An invalid move or direction has been supplied for:\n\tPlayer:supplied direction [A] expected one in [X, Y, Z] as [OpponentWins, Draw, PlayerWins]".to_string()));
+        assert_eq!(rendered(part_one(input)), Err("line 2: invalid round\ncaused by: An invalid move or direction has been supplied for:\n\tPlayer:supplied game move [A] for [player] expected one in [X, Y, Z] as [Rock, Paper, Scissor]".to_string()));
+        assert_eq!(rendered(part_two(input)), Err("line 2: invalid round\ncaused by: An invalid move or direction has been supplied for:\n\tPlayer:supplied direction [A] expected one in [X, Y, Z] as [OpponentWins, Draw, PlayerWins]".to_string()));
     }
 }
@@ -1,51 +1,78 @@
+use advent_of_code::{stream, Error};
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::io::BufRead;
 use std::{collections::hash_map::RandomState, hash::Hash};
 
-pub fn part_one(input: &str) -> Result<u32, String> {
-    use parser::*;
+fn parse_rucksacks<L: AsRef<str>>(
+    lines: impl Iterator<Item = L>,
+) -> impl Iterator<Item = Result<Rucksack, Error>> {
+    lines.enumerate().map(|(index, line)| {
+        line.as_ref().parse::<Rucksack>().map_err(|e| {
+            Error::parse("invalid rucksack")
+                .at_line(index + 1)
+                .with_source(e)
+        })
+    })
+}
 
-    let res: Result<u32, RucksackParsingError> = input
-        .lines()
-        .map(|l| l.parse::<Rucksack>().map(|r| calculate_priorities(&r)))
+fn sum_of_priorities<L: AsRef<str>>(lines: impl Iterator<Item = L>) -> Result<u32, Error> {
+    parse_rucksacks(lines)
+        .map(|rucksack| rucksack.map(|r| calculate_priorities(&r)))
         .fold_while(Ok(0), |acc, elem| match elem {
             Err(err) => itertools::FoldWhile::Done(Err(err)),
             Ok(i) => itertools::FoldWhile::Continue(acc.map(|a| a + i)),
         })
-        .into_inner();
+        .into_inner()
+}
 
-    res.map_err(|e| e.to_string())
+/// Only holds one group of three rucksacks at a time, so it also works on a stream.
+fn sum_of_badge_priorities<L: AsRef<str>>(lines: impl Iterator<Item = L>) -> Result<u32, Error> {
+    parse_rucksacks(lines)
+        .batching(|sub| {
+            let res: Vec<Rucksack> = match sub.take(3).collect() {
+                Ok(res) => res,
+                Err(err) => return Some(Err(err)),
+            };
+            if res.is_empty() {
+                None
+            } else {
+                let res2 = calculate_badge_priorities(&res.iter().collect_vec());
+                Some(Ok(res2))
+            }
+        })
+        .sum()
 }
 
-pub fn part_two(input: &str) -> Result<u32, String> {
-    let res: Result<Vec<Rucksack>, parser::RucksackParsingError> =
-        input.lines().map(|l| l.parse::<Rucksack>()).collect();
-
-    res.map(|rucksacks| {
-        rucksacks
-            .iter()
-            .batching(|sub| {
-                let res: Vec<&Rucksack> = sub.take(3).collect();
-                if res.is_empty() {
-                    None
-                } else {
-                    let res2 = calculate_badge_priorities(&res);
-                    Some(res2)
-                }
-            })
-            .sum()
-    })
-    .map_err(|x| x.to_string())
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    sum_of_priorities(input.lines())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    sum_of_badge_priorities(input.lines())
 }
 
+pub fn part_one_stream(reader: impl BufRead) -> Result<u32, Error> {
+    itertools::process_results(stream::lines(reader), |lines| sum_of_priorities(lines))?
+}
+
+pub fn part_two_stream(reader: impl BufRead) -> Result<u32, Error> {
+    itertools::process_results(stream::lines(reader), |lines| {
+        sum_of_badge_priorities(lines)
+    })?
+}
+
+advent_of_code::solution!(
+    2022,
+    3,
+    part_one,
+    part_two,
+    streaming: part_one_stream,
+    part_two_stream
+);
+
 #[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
 struct Item {
     id: char,
@@ -97,12 +124,13 @@ fn calculate_badge_priorities(rucksack: &[&Rucksack]) -> u32 {
 
 mod parser {
 
-    use crate::Rucksack;
+    use super::Rucksack;
 
     use super::Item;
     use itertools::Itertools;
     use once_cell::sync::Lazy;
     use std::collections::HashSet;
+    use std::fmt;
     use std::str::FromStr;
 
     #[derive(PartialEq, Eq, Debug, Clone)]
@@ -117,33 +145,37 @@ mod parser {
         InvalidItems { invalid: Vec<ItemParsingError> },
     }
 
-    impl ToString for ItemParsingError {
-        fn to_string(&self) -> String {
+    impl fmt::Display for ItemParsingError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             match self {
-                ItemParsingError::MoreThanOneCharacter { original_input } => format!(
+                ItemParsingError::MoreThanOneCharacter { original_input } => write!(
+                    f,
                     "More than one character supplied while parsing item [{}]",
                     original_input
                 ),
-                ItemParsingError::CharacterNotAllowed { original_input } => format!(
+                ItemParsingError::CharacterNotAllowed { original_input } => write!(
+                    f,
                     "Found a character not allowed while parsing item [{}], allowed [{}]",
                     original_input,
                     ALLOWED_ITEM_CHARS.iter().join(",")
                 ),
-                ItemParsingError::Empty => "Cannot parse item from empty string".to_string(),
+                ItemParsingError::Empty => write!(f, "Cannot parse item from empty string"),
             }
         }
     }
 
-    impl ToString for RucksackParsingError {
-        fn to_string(&self) -> String {
+    impl fmt::Display for RucksackParsingError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             match self {
-                RucksackParsingError::UnbalancedItems { original_input } => format!(
+                RucksackParsingError::UnbalancedItems { original_input } => write!(
+                    f,
                     "Items per line should be even, supplied: {}",
                     original_input,
                 ),
                 RucksackParsingError::InvalidItems { invalid } => {
                     let inner_error = invalid.iter().map(|e| e.to_string()).join(",");
-                    format!(
+                    write!(
+                        f,
                         "Some items in rucksack where not parsable [{}]",
                         inner_error
                     )
@@ -152,6 +184,10 @@ mod parser {
         }
     }
 
+    impl std::error::Error for ItemParsingError {}
+
+    impl std::error::Error for RucksackParsingError {}
+
     impl FromStr for Rucksack {
         type Err = RucksackParsingError;
 
@@ -310,13 +346,32 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3);
         assert_eq!(part_one(&input), Ok(157));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3);
         assert_eq!(part_two(&input), Ok(70));
     }
+
+    fn rucksack(rng: &mut advent_of_code::stream::Rng) -> String {
+        let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        // Compartments of equal size, with a digit for an item once in a while.
+        (0..rng.range(1..12) * 2)
+            .map(|_| match rng.one_in(500) {
+                true => '1',
+                false => *rng.pick(&items),
+            })
+            .collect()
+    }
+
+    advent_of_code::stream_tests!(
+        lines(30, rucksack),
+        part_one,
+        part_one_stream,
+        part_two,
+        part_two_stream
+    );
 }
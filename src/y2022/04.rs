@@ -0,0 +1,207 @@
+use advent_of_code::{stream, Error};
+use std::io::BufRead;
+
+fn parse_assignments<L: AsRef<str>>(
+    lines: impl Iterator<Item = L>,
+) -> impl Iterator<Item = Result<CampSectionAssignment, Error>> {
+    lines.enumerate().map(|(index, l)| {
+        l.as_ref()
+            .parse::<CampSectionAssignment>()
+            .map_err(|e| e.at_line(index + 1))
+    })
+}
+
+/// Counts the assignments matching `predicate`, one line at a time.
+fn count_assignments<L: AsRef<str>>(
+    lines: impl Iterator<Item = L>,
+    predicate: fn(&CampSectionAssignment) -> bool,
+) -> Result<u32, Error> {
+    parse_assignments(lines).try_fold(0, |count, assignment| {
+        Ok(count + predicate(&assignment?) as u32)
+    })
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    count_assignments(input.lines(), CampSectionAssignment::fully_contains)
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    count_assignments(input.lines(), CampSectionAssignment::overlap)
+}
+
+pub fn part_one_stream(reader: impl BufRead) -> Result<u32, Error> {
+    itertools::process_results(stream::lines(reader), |lines| {
+        count_assignments(lines, CampSectionAssignment::fully_contains)
+    })?
+}
+
+pub fn part_two_stream(reader: impl BufRead) -> Result<u32, Error> {
+    itertools::process_results(stream::lines(reader), |lines| {
+        count_assignments(lines, CampSectionAssignment::overlap)
+    })?
+}
+
+advent_of_code::solution!(
+    2022,
+    4,
+    part_one,
+    part_two,
+    streaming: part_one_stream,
+    part_two_stream
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CampSection {
+    index: u32,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+
+pub struct CampSectionRange {
+    start: CampSection,
+    end: CampSection,
+}
+
+impl CampSectionRange {
+    fn overlap(&self, other: &CampSectionRange) -> bool {
+        self.end.index >= other.start.index && self.start.index <= other.end.index
+    }
+
+    fn fully_contains(&self, other: &CampSectionRange) -> bool {
+        self.end.index >= other.end.index && self.start.index <= other.start.index
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CampSectionAssignment {
+    left: CampSectionRange,
+    right: CampSectionRange,
+}
+
+impl CampSectionAssignment {
+    fn fully_contains(&self) -> bool {
+        self.left.fully_contains(&self.right) || self.right.fully_contains(&self.left)
+    }
+    fn overlap(&self) -> bool {
+        self.left.overlap(&self.right) || self.right.overlap(&self.left)
+    }
+}
+
+mod parser {
+    use super::{CampSection, CampSectionAssignment, CampSectionRange};
+    use advent_of_code::helpers::parse::{parse_all, unsigned, Res};
+    use advent_of_code::Error;
+    use nom::character::complete::char;
+    use nom::combinator::map;
+    use nom::error::context;
+    use nom::sequence::separated_pair;
+    use std::str::FromStr;
+
+    impl FromStr for CampSectionRange {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            parse_all(parse_camp_section_range, s)
+        }
+    }
+
+    impl FromStr for CampSectionAssignment {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            parse_all(parse_camp_section_assignment, s)
+        }
+    }
+
+    impl FromStr for CampSection {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            parse_all(parse_camp_section, s)
+        }
+    }
+
+    pub fn parse_camp_section(input: &str) -> Res<'_, CampSection> {
+        map(unsigned, |index| CampSection { index })(input)
+    }
+
+    pub fn parse_camp_section_range(input: &str) -> Res<'_, CampSectionRange> {
+        context(
+            "section range",
+            map(
+                separated_pair(parse_camp_section, char('-'), parse_camp_section),
+                |(start, end)| CampSectionRange { start, end },
+            ),
+        )(input)
+    }
+
+    pub fn parse_camp_section_assignment(input: &str) -> Res<'_, CampSectionAssignment> {
+        context(
+            "section assignment",
+            map(
+                separated_pair(
+                    parse_camp_section_range,
+                    char(','),
+                    parse_camp_section_range,
+                ),
+                |(left, right)| CampSectionAssignment { left, right },
+            ),
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 4);
+        assert_eq!(part_one(&input), Ok(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 4);
+        assert_eq!(part_two(&input), Ok(4));
+    }
+
+    fn pair(rng: &mut advent_of_code::stream::Rng) -> String {
+        let (a, c) = (rng.range(1..50), rng.range(1..50));
+        let (b, d) = (a + rng.range(0..50), c + rng.range(0..50));
+        let separator = match rng.one_in(200) {
+            true => ';',
+            false => ',',
+        };
+        format!("{}-{}{}{}-{}", a, b, separator, c, d)
+    }
+
+    advent_of_code::stream_tests!(
+        lines(40, pair),
+        part_one,
+        part_one_stream,
+        part_two,
+        part_two_stream
+    );
+
+    #[test]
+    fn test_parse_error_location() {
+        let error = part_one("2-4,6-8\n2-3,x-5\n").unwrap_err();
+        assert_eq!(error.kind(), advent_of_code::ErrorKind::Parse);
+        assert_eq!(
+            error.location().map(|location| location.to_string()),
+            Some("line 2, column 5".to_string())
+        );
+        assert_eq!(
+            advent_of_code::error::render(&error, "2-4,6-8\n2-3,x-5\n"),
+            "error: expected a digit\n \
+             --> line 2, column 5\n  \
+             |\n\
+             2 | 2-3,x-5\n  \
+             |     ^\n  \
+             = in unsigned integer\n  \
+             = in section range\n  \
+             = in section assignment"
+        );
+    }
+}
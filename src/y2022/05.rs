@@ -0,0 +1,222 @@
+use std::collections::VecDeque;
+
+use advent_of_code::helpers::parse::parse_all;
+use advent_of_code::Error;
+
+use parser::parse_file;
+
+pub fn part_one(input: &str) -> Result<String, Error> {
+    let parsed_data = parse_all(parse_file, input)?;
+
+    let mut state: Vec<VecDeque<char>> = vec![VecDeque::new(); parsed_data.index.0.len()];
+
+    for crate_definition in parsed_data.crates {
+        for (idx, c) in crate_definition.0.iter().enumerate() {
+            if let CrateDefinition::Full(f) = c {
+                state[idx].push_front(*f);
+            }
+        }
+    }
+
+    for MoveDefinition { how_many, from, to } in parsed_data.moves {
+        for _ in 0..how_many {
+            let popped = stack(&mut state, from)?
+                .pop_back()
+                .ok_or_else(|| Error::logic(format!("stack {} is empty", from)))?;
+            stack(&mut state, to)?.push_back(popped)
+        }
+    }
+
+    let mut sol = String::new();
+
+    for s in state {
+        sol.push(*s.back().unwrap_or(&' '));
+    }
+
+    Ok(sol)
+}
+
+pub fn part_two(input: &str) -> Result<String, Error> {
+    let parsed_data = parse_all(parse_file, input)?;
+
+    let mut state: Vec<VecDeque<char>> = vec![VecDeque::new(); parsed_data.index.0.len()];
+
+    for crate_definition in parsed_data.crates {
+        for (idx, c) in crate_definition.0.iter().enumerate() {
+            if let CrateDefinition::Full(f) = c {
+                state[idx].push_front(*f);
+            }
+        }
+    }
+
+    for MoveDefinition { how_many, from, to } in parsed_data.moves {
+        let mut tmp: VecDeque<char> = VecDeque::new();
+        for _ in 0..how_many {
+            let popped = stack(&mut state, from)?
+                .pop_back()
+                .ok_or_else(|| Error::logic(format!("stack {} is empty", from)))?;
+            tmp.push_back(popped);
+        }
+
+        for _ in 0..how_many {
+            let popped = tmp.pop_back().unwrap();
+            stack(&mut state, to)?.push_back(popped)
+        }
+    }
+
+    let mut sol = String::new();
+
+    for s in state {
+        sol.push(*s.back().unwrap_or(&' '));
+    }
+
+    Ok(sol)
+}
+
+/// The stack with the 1-based `number` of a move, which may name a stack that does not exist.
+fn stack(state: &mut [VecDeque<char>], number: u32) -> Result<&mut VecDeque<char>, Error> {
+    let stacks = state.len();
+    (number as usize)
+        .checked_sub(1)
+        .and_then(|index| state.get_mut(index))
+        .ok_or_else(|| {
+            Error::logic(format!(
+                "stack {} does not exist, there are {} stacks",
+                number, stacks
+            ))
+        })
+}
+
+advent_of_code::solution!(2022, 5, part_one, part_two);
+
+#[derive(Debug)]
+pub enum CrateDefinition {
+    Empty,
+    Full(char),
+}
+
+#[derive(Debug)]
+pub struct CratesDefinition(Vec<CrateDefinition>);
+
+#[derive(Debug)]
+pub struct IndexDefinition(Vec<u32>);
+
+#[derive(Debug)]
+pub struct MoveDefinition {
+    how_many: u32,
+    from: u32,
+    to: u32,
+}
+
+#[derive(Debug)]
+pub struct FileDefinition {
+    crates: Vec<CratesDefinition>,
+    index: IndexDefinition,
+    moves: Vec<MoveDefinition>,
+}
+
+mod parser {
+    use super::{
+        CrateDefinition, CratesDefinition, FileDefinition, IndexDefinition, MoveDefinition,
+    };
+    use advent_of_code::helpers::parse::{lines_of, unsigned, Res};
+    use nom::branch::alt;
+    use nom::bytes::complete::tag;
+    use nom::character::complete::{anychar, char, newline, space0, space1};
+    use nom::combinator::map;
+    use nom::error::context;
+    use nom::multi::{count, many1, separated_list1};
+    use nom::sequence::{preceded, terminated, tuple};
+
+    pub fn parse_empty_crate(input: &str) -> Res<'_, CrateDefinition> {
+        map(count(char(' '), 3), |_| CrateDefinition::Empty)(input)
+    }
+
+    pub fn parse_crate(input: &str) -> Res<'_, CrateDefinition> {
+        map(terminated(preceded(char('['), anychar), char(']')), |c| {
+            CrateDefinition::Full(c)
+        })(input)
+    }
+
+    pub fn parse_crates_definition(input: &str) -> Res<'_, CratesDefinition> {
+        context(
+            "crates",
+            map(
+                separated_list1(char(' '), alt((parse_empty_crate, parse_crate))),
+                CratesDefinition,
+            ),
+        )(input)
+    }
+
+    pub fn parse_index_definition(input: &str) -> Res<'_, IndexDefinition> {
+        context(
+            "stack numbers",
+            map(
+                terminated(preceded(space0, separated_list1(space1, unsigned)), space0),
+                IndexDefinition,
+            ),
+        )(input)
+    }
+
+    pub fn parse_move_definition(input: &str) -> Res<'_, MoveDefinition> {
+        context(
+            "move",
+            map(
+                tuple((
+                    tag("move"),
+                    terminated(preceded(space1, unsigned), space1),
+                    tag("from"),
+                    terminated(preceded(space1, unsigned), space1),
+                    tag("to"),
+                    preceded(space1, unsigned),
+                )),
+                |(_, how_many, _, from, _, to)| MoveDefinition { how_many, from, to },
+            ),
+        )(input)
+    }
+
+    pub fn parse_file(input: &str) -> Res<'_, FileDefinition> {
+        map(
+            tuple((
+                many1(terminated(parse_crates_definition, newline)),
+                terminated(parse_index_definition, newline),
+                newline,
+                lines_of(parse_move_definition),
+            )),
+            |(crates, index, _, moves)| FileDefinition {
+                crates,
+                index,
+                moves,
+            },
+        )(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        assert_eq!(part_one(&input), Ok("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        assert_eq!(part_two(&input), Ok("MCD".to_string()));
+    }
+
+    #[test]
+    fn test_missing_stack() {
+        let input = advent_of_code::read_file("examples", 2022, 5);
+        for bad_move in ["move 1 from 0 to 1", "move 1 from 2 to 4"] {
+            let input = format!("{}\n{}", input.trim_end(), bad_move);
+            for outcome in [part_one(&input), part_two(&input)] {
+                let error = outcome.unwrap_err();
+                assert_eq!(error.kind(), advent_of_code::ErrorKind::Logic);
+            }
+        }
+    }
+}
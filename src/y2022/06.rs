@@ -0,0 +1,114 @@
+use advent_of_code::{stream, Error};
+use std::io::BufRead;
+
+#[derive(Debug, Clone)]
+struct FoldState<S, const C: usize> {
+    buffer: [S; C],
+    position: usize,
+}
+
+impl<S, const C: usize> FoldState<S, C>
+where
+    S: Sized + Default + Copy + Eq + std::fmt::Debug,
+{
+    fn zero() -> Self {
+        FoldState {
+            buffer: [S::default(); C],
+            position: 0,
+        }
+    }
+
+    fn found(&mut self, c: S) -> bool {
+        self.rotate_buffer(c);
+
+        self.position += 1;
+
+        if self.position >= C && self.distinct() {
+            return true;
+        }
+
+        false
+    }
+
+    fn distinct(&self) -> bool {
+        for i in 0..C {
+            for j in 0..C {
+                if j != i && self.buffer[i] == self.buffer[j] {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn rotate_buffer(&mut self, c: S) {
+        for x in 1..C {
+            self.buffer[x - 1] = self.buffer[x];
+        }
+        self.buffer[C - 1] = c;
+    }
+}
+
+/// The number of characters up to and including the first `C` distinct ones in a row.
+fn find_marker<const C: usize>(chars: impl Iterator<Item = char>) -> Result<u32, Error> {
+    let mut state: FoldState<char, C> = FoldState::<char, C>::zero();
+
+    for c in chars {
+        if state.found(c) {
+            return Result::Ok(state.position as u32);
+        }
+    }
+
+    Err(Error::logic("Not found"))
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    find_marker::<4>(input.chars())
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    find_marker::<14>(input.chars())
+}
+
+/// Stops reading at the marker.
+pub fn part_one_stream(reader: impl BufRead) -> Result<u32, Error> {
+    itertools::process_results(stream::chars(reader), |chars| find_marker::<4>(chars))?
+}
+
+pub fn part_two_stream(reader: impl BufRead) -> Result<u32, Error> {
+    itertools::process_results(stream::chars(reader), |chars| find_marker::<14>(chars))?
+}
+
+advent_of_code::solution!(
+    2022,
+    6,
+    part_one,
+    part_two,
+    streaming: part_one_stream,
+    part_two_stream
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::example_tests!(2022, 6, part_one, part_two);
+
+    fn generate(rng: &mut advent_of_code::stream::Rng) -> String {
+        // Few distinct characters, so the markers are late or missing, and some of them are wider than a byte.
+        let alphabet: Vec<char> = ('a'..='p').chain(['é', '€', '😀']).collect();
+        let len = rng.range(0..200);
+        let alphabet = &alphabet[..rng.range(3..alphabet.len() as u64 + 1) as usize];
+        let mut input: String = (0..len).map(|_| *rng.pick(alphabet)).collect();
+        input.push('\n');
+        input
+    }
+
+    advent_of_code::stream_tests!(
+        generate,
+        part_one,
+        part_one_stream,
+        part_two,
+        part_two_stream
+    );
+}
@@ -1,15 +1,17 @@
 use std::{cell::RefCell, rc::Rc};
 
+use advent_of_code::helpers::parse::parse_all;
+use advent_of_code::Error;
 use itertools::Itertools;
-use nom::Finish;
 
-use crate::{parser::parse_commands, tree::TreeNode};
+use parser::parse_commands;
+use tree::TreeNode;
 
 mod tree {
     use std::cell::RefCell;
     use std::rc::Rc;
 
-    use crate::{DirNode, FileNode, FsNode};
+    use super::{DirNode, FileNode, FsNode};
 
     #[derive(PartialEq)]
     pub struct TreeNode<'a> {
@@ -25,8 +27,6 @@ mod tree {
                 FsNode::File(FileNode { name, size }) => name.to_string() + "|" + &size.to_string(),
             };
 
-            if self.children.is_empty() {}
-
             let mut s = String::new();
             s.push_str(&data);
             s.push('[');
@@ -64,70 +64,63 @@ mod tree {
 
 mod parser {
 
+    use advent_of_code::helpers::parse::{lines_of, unsigned, Res};
     use nom::branch::alt;
     use nom::bytes::complete::{tag, take_while1};
-    use nom::character::complete::{digit1, line_ending, space1};
-    use nom::combinator::{map, map_res};
-    use nom::error::VerboseError;
-    use nom::multi::{many1, separated_list1};
+    use nom::character::complete::{line_ending, space1};
+    use nom::combinator::map;
+    use nom::error::context;
     use nom::sequence::{preceded, terminated, tuple};
-    use nom::IResult;
-
-    use crate::{Commands, Definition};
 
-    type Res<'a, U> = IResult<&'a str, U, VerboseError<&'a str>>;
+    use super::{Commands, Definition};
 
-    pub fn take_str(input: &str) -> Res<&str> {
+    pub fn take_str(input: &str) -> Res<'_, &str> {
         take_while1(|c| c != '\n')(input)
     }
 
-    pub fn parse_cd_command(input: &str) -> Res<Commands> {
+    pub fn parse_cd_command(input: &str) -> Res<'_, Commands<'_>> {
         map(parse_cd, |directory| Commands::Cd { directory })(input)
     }
 
-    pub fn parse_ls_command(input: &str) -> Res<Commands> {
+    pub fn parse_ls_command(input: &str) -> Res<'_, Commands<'_>> {
         map(parse_ls, |content| Commands::Ls { content })(input)
     }
 
-    pub fn parse_cd(input: &str) -> Res<&str> {
+    pub fn parse_cd(input: &str) -> Res<'_, &str> {
         preceded(tag("$ cd "), take_str)(input)
     }
 
-    pub fn parse_ls(input: &str) -> Res<Vec<Definition>> {
+    pub fn parse_ls(input: &str) -> Res<'_, Vec<Definition<'_>>> {
         map(
             tuple((
                 terminated(tag("$ ls"), line_ending),
-                separated_list1(line_ending, parse_ls_output),
+                lines_of(parse_ls_output),
             )),
             |d| d.1,
         )(input)
     }
 
-    pub fn parse_dir(input: &str) -> Res<Definition> {
+    pub fn parse_dir(input: &str) -> Res<'_, Definition<'_>> {
         map(preceded(tag("dir "), take_str), |x| Definition::Directory {
             name: x,
         })(input)
     }
 
-    pub fn parse_file_line(input: &str) -> Res<Definition> {
+    pub fn parse_file_line(input: &str) -> Res<'_, Definition<'_>> {
         map(
-            tuple((parse_unsigned_integer, preceded(space1, take_str))),
+            tuple((unsigned, preceded(space1, take_str))),
             |(size, name)| Definition::File { size, name },
         )(input)
     }
 
-    pub fn parse_ls_output(input: &str) -> Res<Definition> {
+    pub fn parse_ls_output(input: &str) -> Res<'_, Definition<'_>> {
         alt((parse_file_line, parse_dir))(input)
     }
 
-    pub fn parse_unsigned_integer(input: &str) -> Res<u32> {
-        map_res(digit1, |c: &str| c.parse::<u32>())(input)
-    }
-
-    pub fn parse_commands(input: &str) -> Res<Vec<Commands>> {
-        many1(terminated(
+    pub fn parse_commands(input: &str) -> Res<'_, Vec<Commands<'_>>> {
+        lines_of(context(
+            "command",
             alt((parse_cd_command, parse_ls_command)),
-            line_ending,
         ))(input)
     }
 }
@@ -158,8 +151,8 @@ pub enum Commands<'a> {
     Ls { content: Vec<Definition<'a>> },
 }
 
-pub fn part_one(input: &str) -> Result<u32, String> {
-    let (_, parsed) = parse_commands(input).finish().map_err(|e| e.to_string())?;
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let parsed = parse_all(parse_commands, input)?;
 
     let root: Rc<std::cell::RefCell<TreeNode>> = TreeNode::dir("/");
 
@@ -178,12 +171,9 @@ fn build_tree<'a>(root: &'a Rc<RefCell<TreeNode<'a>>>, parsed: Vec<Commands<'a>>
             Commands::Cd { directory: ".." } => {
                 let x = Rc::clone(&tree);
                 let a = x.borrow_mut();
-                match &a.parent {
-                    Some(a) => {
-                        tree = Rc::clone(a);
-                    }
-                    None => {}
-                };
+                if let Some(a) = &a.parent {
+                    tree = Rc::clone(a);
+                }
             }
             Commands::Cd { directory } => {
                 let x = Rc::clone(&tree);
@@ -222,7 +212,7 @@ fn build_tree<'a>(root: &'a Rc<RefCell<TreeNode<'a>>>, parsed: Vec<Commands<'a>>
     }
 }
 
-pub fn calculate_size(node: Rc<RefCell<TreeNode>>, sum: &mut u32) -> Result<u32, String> {
+pub fn calculate_size(node: Rc<RefCell<TreeNode>>, sum: &mut u32) -> Result<u32, Error> {
     let node = node.borrow();
 
     let mut size: u32 = 0;
@@ -250,7 +240,7 @@ pub fn calculate_size(node: Rc<RefCell<TreeNode>>, sum: &mut u32) -> Result<u32,
 pub fn find_candidate(
     node: Rc<RefCell<TreeNode>>,
     candidates: &mut Vec<u32>,
-) -> Result<u32, String> {
+) -> Result<u32, Error> {
     let node = node.borrow();
 
     let mut size: u32 = 0;
@@ -273,8 +263,8 @@ pub fn find_candidate(
     Ok(size)
 }
 
-pub fn part_two(input: &str) -> Result<u32, String> {
-    let (_, parsed) = parse_commands(input).finish().map_err(|e| e.to_string())?;
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let parsed = parse_all(parse_commands, input)?;
 
     let root: Rc<std::cell::RefCell<TreeNode>> = TreeNode::dir("/");
 
@@ -285,7 +275,7 @@ pub fn part_two(input: &str) -> Result<u32, String> {
 
     let total_space = 70000000;
 
-    let free =  total_space - used;
+    let free = total_space - used;
 
     let to_be_freed = 30000000 - free;
 
@@ -295,24 +285,21 @@ pub fn part_two(input: &str) -> Result<u32, String> {
         .sorted()
         .next()
         .copied()
-        .ok_or_else(|| "not_found".to_string())
+        .ok_or_else(|| Error::logic("no directory is large enough"))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2022, 7, part_one, part_two);
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::{parse_cd, parse_dir, parse_file_line, parse_ls, parse_ls_output};
+    use super::parser::{parse_cd, parse_dir, parse_file_line, parse_ls, parse_ls_output};
+    use nom::Finish;
 
     use super::*;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_one(&input), Ok(95437));
     }
 
@@ -418,7 +405,7 @@ dir wlqhpwqv";
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_two(&input), Ok(24933642));
     }
 }
@@ -0,0 +1,134 @@
+use advent_of_code::helpers::geom::Direction;
+use advent_of_code::helpers::{Grid, Position};
+use advent_of_code::Error;
+
+fn load(input: &str) -> Result<Grid<u32>, Error> {
+    Ok(Grid::parse(input, |c| c.to_digit(10))?)
+}
+
+/// Walks from a tree towards the edge until a tree at least as high blocks the view.
+///
+/// Returns whether the edge was reached and the number of trees seen, including the blocking one.
+fn view(grid: &Grid<u32>, tree: Position, direction: Direction) -> (bool, u32) {
+    let height = grid[tree];
+    let mut steps = 0;
+
+    for (_, &current) in grid.ray(tree, direction) {
+        steps += 1;
+        if current >= height {
+            return (false, steps);
+        }
+    }
+
+    (true, steps)
+}
+
+fn scenic_score(grid: &Grid<u32>, tree: Position) -> u32 {
+    Direction::CARDINAL
+        .iter()
+        .map(|&direction| view(grid, tree, direction).1)
+        .product()
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let grid = load(input)?;
+
+    let visible_count = grid
+        .positions()
+        .filter(|&tree| {
+            Direction::CARDINAL
+                .iter()
+                .any(|&direction| view(&grid, tree, direction).0)
+        })
+        .count();
+
+    Ok(visible_count as u32)
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let grid = load(input)?;
+
+    Ok(grid
+        .positions()
+        .map(|tree| scenic_score(&grid, tree))
+        .max()
+        .unwrap_or(0))
+}
+
+advent_of_code::solution!(2022, 8, part_one, part_two);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::helpers::geom::Point;
+
+    const INPUT: &str = "30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn test_view_east() {
+        let grid = load(INPUT).unwrap();
+
+        assert_eq!(view(&grid, Point::new(2, 3), Direction::East), (false, 2));
+        assert_eq!(view(&grid, Point::new(2, 2), Direction::East), (false, 1));
+        assert_eq!(view(&grid, Point::new(3, 4), Direction::East), (true, 1));
+    }
+
+    #[test]
+    fn test_view_west() {
+        let grid = load(INPUT).unwrap();
+
+        assert_eq!(view(&grid, Point::new(0, 0), Direction::West), (true, 0));
+        assert_eq!(view(&grid, Point::new(0, 2), Direction::West), (true, 0));
+    }
+
+    #[test]
+    fn test_view_north() {
+        let grid = load(INPUT).unwrap();
+
+        assert_eq!(view(&grid, Point::new(2, 1), Direction::North), (true, 1));
+        assert_eq!(view(&grid, Point::new(0, 2), Direction::North), (true, 2));
+    }
+
+    #[test]
+    fn test_view_south() {
+        let grid = load(INPUT).unwrap();
+
+        assert_eq!(view(&grid, Point::new(2, 1), Direction::South), (false, 2));
+        assert_eq!(view(&grid, Point::new(0, 2), Direction::South), (true, 2));
+    }
+
+    #[test]
+    fn test_scenic_score() {
+        let grid = load(INPUT).unwrap();
+
+        assert_eq!(scenic_score(&grid, Point::new(2, 3)), 8);
+        assert_eq!(scenic_score(&grid, Point::new(2, 1)), 4);
+    }
+
+    #[test]
+    fn test_rectangular() {
+        let wide = "30373\n25512\n65332\n";
+        assert_eq!(part_one(wide), Ok(14));
+        assert_eq!(part_two(wide), Ok(2));
+
+        let tall = "326\n055\n353\n715\n322\n";
+        assert_eq!(part_one(tall), Ok(14));
+        assert_eq!(part_two(tall), Ok(2));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", 2022, 8);
+        assert_eq!(part_one(&input), Ok(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", 2022, 8);
+        assert_eq!(part_two(&input), Ok(8));
+    }
+}
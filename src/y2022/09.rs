@@ -0,0 +1,212 @@
+use std::collections::HashSet;
+
+use advent_of_code::helpers::geom::{Direction, Point};
+use advent_of_code::helpers::parse::parse_all;
+use advent_of_code::{stream, Error};
+use parser::{parse_file, parse_move};
+use std::io::BufRead;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Move(Direction, u32);
+
+type Position = Point<i32>;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Rope {
+    knots: Vec<Position>,
+}
+
+impl Rope {
+    fn update(&mut self, dir: Direction) -> Position {
+        self.knots[0] += dir.offset();
+
+        for i in 0..self.knots.len() - 1 {
+            let h = self.knots[i];
+            let t = self.knots[i + 1];
+
+            self.knots[i + 1] = Self::calculate_new_tail_position(h, t);
+        }
+
+        *self.knots.last().unwrap()
+    }
+
+    fn simulate(&mut self, parsed: impl IntoIterator<Item = Move>) -> u32 {
+        let mut tail_positions: HashSet<Position> = HashSet::new();
+        tail_positions.insert(Point::ORIGIN);
+        for Move(dir, steps) in parsed {
+            for _ in 0..steps {
+                let new_tail_position = self.update(dir);
+                tail_positions.insert(new_tail_position);
+            }
+        }
+        tail_positions.len() as u32
+    }
+
+    fn calculate_new_tail_position(head: Position, tail: Position) -> Position {
+        if head.chebyshev(tail) > 1 {
+            tail.step_towards(head)
+        } else {
+            tail
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u32, Error> {
+    let parsed = parse_all(parse_file, input)?;
+
+    let mut rope = Rope {
+        knots: vec![Point::ORIGIN; 2],
+    };
+
+    let tail_positions = rope.simulate(parsed);
+
+    Ok(tail_positions as u32)
+}
+
+pub fn part_two(input: &str) -> Result<u32, Error> {
+    let parsed = parse_all(parse_file, input)?;
+
+    let mut rope = Rope {
+        knots: vec![Point::ORIGIN; 10],
+    };
+
+    let tail_positions = rope.simulate(parsed);
+
+    Ok(tail_positions as u32)
+}
+
+/// Simulates a rope of `knots` knots, parsing one move at a time. Like `parse_file`, it needs at least one move.
+fn simulate_stream(reader: impl BufRead, knots: usize) -> Result<u32, Error> {
+    let moves = stream::lines1(reader)
+        .enumerate()
+        .map(|(index, line)| parse_all(parse_move, &line?).map_err(|e| e.at_line(index + 1)));
+
+    let mut rope = Rope {
+        knots: vec![Point::ORIGIN; knots],
+    };
+
+    itertools::process_results(moves, |moves| rope.simulate(moves))
+}
+
+pub fn part_one_stream(reader: impl BufRead) -> Result<u32, Error> {
+    simulate_stream(reader, 2)
+}
+
+pub fn part_two_stream(reader: impl BufRead) -> Result<u32, Error> {
+    simulate_stream(reader, 10)
+}
+
+mod parser {
+
+    use advent_of_code::helpers::parse::{lines_of, unsigned, Res};
+    use nom::branch::alt;
+
+    use nom::character::complete::{char, space1};
+    use nom::combinator::map;
+    use nom::error::context;
+    use nom::sequence::{terminated, tuple};
+
+    use super::{Direction, Move};
+
+    fn parse_up(input: &str) -> Res<'_, Direction> {
+        map(char('U'), |_| Direction::North)(input)
+    }
+
+    fn parse_down(input: &str) -> Res<'_, Direction> {
+        map(char('D'), |_| Direction::South)(input)
+    }
+
+    fn parse_left(input: &str) -> Res<'_, Direction> {
+        map(char('L'), |_| Direction::West)(input)
+    }
+
+    fn parse_right(input: &str) -> Res<'_, Direction> {
+        map(char('R'), |_| Direction::East)(input)
+    }
+
+    fn parse_direction(input: &str) -> Res<'_, Direction> {
+        alt((parse_left, parse_right, parse_up, parse_down))(input)
+    }
+
+    pub fn parse_move(input: &str) -> Res<'_, Move> {
+        context(
+            "move",
+            map(
+                tuple((terminated(parse_direction, space1), unsigned)),
+                |(direction, steps)| Move(direction, steps),
+            ),
+        )(input)
+    }
+
+    pub fn parse_file(input: &str) -> Res<'_, Vec<Move>> {
+        lines_of(parse_move)(input)
+    }
+}
+
+advent_of_code::solution!(
+    2022,
+    9,
+    part_one,
+    part_two,
+    streaming: part_one_stream,
+    part_two_stream
+);
+
+#[cfg(test)]
+mod tests {
+    use nom::Finish;
+
+    use super::*;
+
+    #[test]
+    fn test_part_parse_file() {
+        let input = advent_of_code::examples::read(2022, 9, "a");
+        let res = super::parser::parse_file(input.trim_end())
+            .finish()
+            .map_err(|e| e.to_string());
+
+        let expected: Vec<Move> = vec![
+            Move(Direction::East, 4),
+            Move(Direction::North, 4),
+            Move(Direction::West, 3),
+            Move(Direction::South, 1),
+            Move(Direction::East, 4),
+            Move(Direction::South, 1),
+            Move(Direction::West, 5),
+            Move(Direction::East, 2),
+        ];
+
+        assert_eq!(res, Ok(("", expected)));
+    }
+
+    #[test]
+    fn test_malformed_line_is_an_error() {
+        let error = part_one("R 4\nU 4\nX 3\nD 1\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: unexpected input in \"X 3\""
+        );
+        assert!(part_one("R 4\nU 4").is_ok());
+
+        let error = part_one("R 4\nU x\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: expected a digit");
+    }
+
+    advent_of_code::example_tests!(2022, 9, part_one, part_two);
+
+    fn motion(rng: &mut advent_of_code::stream::Rng) -> String {
+        let direction = match rng.one_in(200) {
+            true => "X",
+            false => rng.pick(&["U", "D", "L", "R"]),
+        };
+        format!("{} {}", direction, rng.range(1..20))
+    }
+
+    advent_of_code::stream_tests!(
+        lines(40, motion),
+        part_one,
+        part_one_stream,
+        part_two,
+        part_two_stream
+    );
+}
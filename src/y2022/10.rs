@@ -1,20 +1,22 @@
-use nom::Finish;
-use parser::parse_program;
-
-struct Cpu<'a> {
+use advent_of_code::helpers::geom::Point;
+use advent_of_code::helpers::parse::parse_all;
+use advent_of_code::helpers::Grid;
+use advent_of_code::{stream, Error};
+use parser::{parse_instruction, parse_program};
+use std::io::BufRead;
+
+struct Cpu<I> {
     x: i32,
-    program: &'a Vec<Instruction>,
-    program_counter: usize,
+    program: I,
     cycles_remaining: u32,
     buffered_instruction: Instruction,
 }
 
-impl<'a> Cpu<'a> {
-    fn new(program: &'a Vec<Instruction>) -> Cpu {
+impl<I: Iterator<Item = Instruction>> Cpu<I> {
+    fn new(program: I) -> Cpu<I> {
         Cpu {
             x: 1,
             program,
-            program_counter: 0,
             cycles_remaining: 0,
             buffered_instruction: Instruction::Noop,
         }
@@ -36,11 +38,10 @@ impl<'a> Cpu<'a> {
             return true;
         }
 
-        if self.program_counter == self.program.len() {
-            return false;
-        }
-
-        self.buffered_instruction = self.program[self.program_counter];
+        self.buffered_instruction = match self.program.next() {
+            Some(instruction) => instruction,
+            None => return false,
+        };
 
         match self.buffered_instruction {
             Instruction::Noop => {
@@ -51,16 +52,12 @@ impl<'a> Cpu<'a> {
             }
         };
 
-        self.program_counter += 1;
-
         true
     }
 }
 
-pub fn part_one(input: &str) -> Result<i32, String> {
-    let (_, program) = parse_program(input).finish().map_err(|e| e.to_string())?;
-
-    let mut cpu = Cpu::new(&program.buffer);
+fn signal_strength(program: impl Iterator<Item = Instruction>) -> i32 {
+    let mut cpu = Cpu::new(program);
 
     let mut cycle = 1;
 
@@ -83,50 +80,26 @@ pub fn part_one(input: &str) -> Result<i32, String> {
         }
     }
 
-    Ok(strength)
+    strength
 }
 
-pub fn part_two(input: &str) -> Result<String, String> {
-    let (_, program) = parse_program(input).finish().map_err(|e| e.to_string())?;
+const SCREEN_WIDTH: usize = 40;
+const SCREEN_HEIGHT: usize = 6;
 
-    let mut cpu = Cpu::new(&program.buffer);
+fn render_screen(program: impl Iterator<Item = Instruction>) -> String {
+    let mut cpu = Cpu::new(program);
 
-    let mut cycle = 1;
+    let mut cycle: usize = 1;
 
-    let mut indexes: [bool; 240] = [false; 240];
+    let mut screen = Grid::new(SCREEN_WIDTH, SCREEN_HEIGHT, '.');
 
     loop {
-        let row = (cycle - 1) % 40;
-        let column = (cycle - 1) / 40;
-
-        let left_position = cpu.x - 1;
-        let middle_position = cpu.x;
-        let right_position = cpu.x + 1;
-
-        let mut sprite_position: [bool; 40] = [false; 40];
-
-        if (0..40).contains(&left_position) {
-            sprite_position[left_position as usize] = true;
-        }
-
-        if (0..40).contains(&middle_position) {
-            sprite_position[middle_position as usize] = true;
-        }
-
-        if (0..40).contains(&right_position) {
-            sprite_position[right_position as usize] = true;
-        }
-
-        if left_position == row {
-            indexes[(left_position + column * 40) as usize] = true;
-        }
-
-        if right_position == row {
-            indexes[(right_position + column * 40) as usize] = true;
-        }
+        let column = (cycle - 1) % SCREEN_WIDTH;
+        let row = (cycle - 1) / SCREEN_WIDTH;
 
-        if middle_position == row {
-            indexes[(middle_position + column * 40) as usize] = true;
+        // the sprite is three pixels wide, centered on `x`.
+        if (cpu.x - column as i32).abs() <= 1 {
+            screen.set(Point::new(column, row), '#');
         }
 
         cycle += 1;
@@ -135,29 +108,47 @@ pub fn part_two(input: &str) -> Result<String, String> {
         }
     }
 
-    let mut result = String::new();
+    screen.to_string()
+}
 
-    (0..240).for_each(|i| {
-        if indexes[i] {
-            result.push('#');
-        } else {
-            result.push('.');
-        }
+/// The instructions of `reader`, parsed one line at a time. Like `parse_program`, it needs at least one.
+fn parse_instructions(reader: impl BufRead) -> impl Iterator<Item = Result<Instruction, Error>> {
+    stream::lines1(reader)
+        .enumerate()
+        .map(|(index, line)| parse_all(parse_instruction, &line?).map_err(|e| e.at_line(index + 1)))
+}
 
-        if i != 0 && (i + 1) % 40 == 0 {
-            result.push('\n')
-        }
-    });
+pub fn part_one(input: &str) -> Result<i32, Error> {
+    let program = parse_all(parse_program, input)?;
+
+    Ok(signal_strength(program.buffer.into_iter()))
+}
+
+pub fn part_two(input: &str) -> Result<String, Error> {
+    let program = parse_all(parse_program, input)?;
 
-    Ok(result)
+    Ok(render_screen(program.buffer.into_iter()))
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+pub fn part_one_stream(reader: impl BufRead) -> Result<i32, Error> {
+    itertools::process_results(parse_instructions(reader), |program| {
+        signal_strength(program)
+    })
 }
 
+pub fn part_two_stream(reader: impl BufRead) -> Result<String, Error> {
+    itertools::process_results(parse_instructions(reader), |program| render_screen(program))
+}
+
+advent_of_code::solution!(
+    2022,
+    10,
+    part_one,
+    part_two,
+    streaming: part_one_stream,
+    part_two_stream
+);
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Instruction {
     Noop,
@@ -171,51 +162,47 @@ pub struct Program {
 
 mod parser {
 
+    use advent_of_code::helpers::parse::{lines_of, signed, Res};
     use nom::branch::alt;
 
     use nom::bytes::complete::tag;
-    use nom::character::complete::{i32 as number, line_ending, space1};
+    use nom::character::complete::space1;
     use nom::combinator::map;
-    use nom::error::VerboseError;
-    use nom::multi::separated_list1;
+    use nom::error::context;
     use nom::sequence::tuple;
-    use nom::IResult;
 
-    use crate::{Instruction, Program};
+    use super::{Instruction, Program};
 
-    type Res<'a, U> = IResult<&'a str, U, VerboseError<&'a str>>;
-
-    fn parse_instruction(input: &str) -> Res<Instruction> {
-        alt((parse_noop, parse_addx))(input)
+    pub fn parse_instruction(input: &str) -> Res<'_, Instruction> {
+        context("instruction", alt((parse_noop, parse_addx)))(input)
     }
 
-    fn parse_noop(input: &str) -> Res<Instruction> {
+    fn parse_noop(input: &str) -> Res<'_, Instruction> {
         map(tag("noop"), |_| Instruction::Noop)(input)
     }
 
-    fn parse_addx(input: &str) -> Res<Instruction> {
-        map(tuple((tag("addx"), space1, number)), |(_, _, argument)| {
+    fn parse_addx(input: &str) -> Res<'_, Instruction> {
+        map(tuple((tag("addx"), space1, signed)), |(_, _, argument)| {
             Instruction::Addx(argument)
         })(input)
     }
 
-    pub fn parse_program(input: &str) -> Res<Program> {
-        map(separated_list1(line_ending, parse_instruction), |buffer| {
-            Program { buffer }
-        })(input)
+    pub fn parse_program(input: &str) -> Res<'_, Program> {
+        map(lines_of(parse_instruction), |buffer| Program { buffer })(input)
     }
 }
+
 #[cfg(test)]
 mod tests {
     use nom::Finish;
 
-    use crate::parser::parse_program;
+    use super::parser::parse_program;
 
     use super::*;
 
     #[test]
     fn test_load_program() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10);
 
         let result: Result<(&str, Program), String> =
             parse_program(&input).finish().map_err(|e| e.to_string());
@@ -378,21 +365,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10);
 
         assert_eq!(part_one(&input), Ok(13140));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10);
 
         let outcome = part_two(&input);
 
-        let result = outcome.clone().unwrap();
-
-        println!("{}", result);
-
         let expected = "##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
 ####....####....####....####....####....
@@ -403,4 +386,21 @@ mod tests {
 
         assert_eq!(outcome, Ok(expected.to_string()));
     }
+
+    fn instruction(rng: &mut advent_of_code::stream::Rng) -> String {
+        // Mostly additions, so the register moves, and an `addx` without its value once in a while.
+        match rng.range(0..500) {
+            0 => "addx".to_string(),
+            1..=199 => "noop".to_string(),
+            _ => format!("addx {}", rng.range(0..41) as i64 - 20),
+        }
+    }
+
+    advent_of_code::stream_tests!(
+        lines(160, instruction),
+        part_one,
+        part_one_stream,
+        part_two,
+        part_two_stream
+    );
 }
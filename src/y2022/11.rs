@@ -1,9 +1,10 @@
+use advent_of_code::helpers::parse::parse_all;
+use advent_of_code::Error;
 use itertools::Itertools;
-use nom::Finish;
 use parser::parse_monkeys;
 
-pub fn part_one(input: &str) -> Result<u64, String> {
-    let (_, monkeys) = parse_monkeys(input).finish().map_err(|e| e.to_string())?;
+pub fn part_one(input: &str) -> Result<u64, Error> {
+    let monkeys = parse_all(parse_monkeys, input)?;
 
     let mut mutable_monkeys = monkeys;
 
@@ -54,8 +55,8 @@ pub fn part_one(input: &str) -> Result<u64, String> {
     Ok(inspections.iter().sorted().rev().take(2).product())
 }
 
-pub fn part_two(input: &str) -> Result<u64, String> {
-    let (_, monkeys) = parse_monkeys(input).finish().map_err(|e| e.to_string())?;
+pub fn part_two(input: &str) -> Result<u64, Error> {
+    let monkeys = parse_all(parse_monkeys, input)?;
 
     let mut mutable_monkeys = monkeys;
 
@@ -108,11 +109,7 @@ pub fn part_two(input: &str) -> Result<u64, String> {
     Ok(inspections.iter().sorted().rev().take(2).product())
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
+advent_of_code::solution!(2022, 11, part_one, part_two);
 
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct Monkey {
@@ -154,20 +151,18 @@ pub enum Operation {
 
 mod parser {
 
+    use advent_of_code::helpers::parse::{blocks_of, unsigned as number, Res};
     use itertools::Itertools;
     use nom::branch::alt;
 
     use nom::bytes::complete::tag;
-    use nom::character::complete::{line_ending, space1, u64 as number};
+    use nom::character::complete::{line_ending, space1};
     use nom::combinator::map;
-    use nom::error::VerboseError;
+    use nom::error::context;
     use nom::multi::separated_list1;
     use nom::sequence::{preceded, terminated, tuple};
-    use nom::IResult;
-
-    use crate::{Element, Expression, Item, Monkey, Operation, Test};
 
-    type Res<'a, U> = IResult<&'a str, U, VerboseError<&'a str>>;
+    use super::{Element, Expression, Item, Monkey, Operation, Test};
 
     struct MonkeyLine(u64);
     struct StartingItemsLine(Vec<u64>);
@@ -180,14 +175,14 @@ mod parser {
 
     struct FalseLine(u64);
 
-    fn parse_monkey_line(input: &str) -> Res<MonkeyLine> {
+    fn parse_monkey_line(input: &str) -> Res<'_, MonkeyLine> {
         map(
             terminated(preceded(tag("Monkey "), number), tag(":")),
             MonkeyLine,
         )(input)
     }
 
-    fn parse_starting_items_line(input: &str) -> Res<StartingItemsLine> {
+    fn parse_starting_items_line(input: &str) -> Res<'_, StartingItemsLine> {
         map(
             preceded(
                 tag("  Starting items: "),
@@ -197,88 +192,91 @@ mod parser {
         )(input)
     }
 
-    fn parse_element(input: &str) -> Res<Element> {
+    fn parse_element(input: &str) -> Res<'_, Element> {
         alt((parse_old, parse_literal))(input)
     }
 
-    fn parse_old(input: &str) -> Res<Element> {
+    fn parse_old(input: &str) -> Res<'_, Element> {
         map(tag("old"), |_| Element::Old)(input)
     }
 
-    fn parse_literal(input: &str) -> Res<Element> {
+    fn parse_literal(input: &str) -> Res<'_, Element> {
         map(number, Element::Literal)(input)
     }
 
-    fn parse_add(input: &str) -> Res<Operation> {
+    fn parse_add(input: &str) -> Res<'_, Operation> {
         map(tag("+"), |_| Operation::Add)(input)
     }
 
-    fn parse_multiply(input: &str) -> Res<Operation> {
+    fn parse_multiply(input: &str) -> Res<'_, Operation> {
         map(tag("*"), |_| Operation::Multiply)(input)
     }
 
-    fn parse_operation(input: &str) -> Res<Operation> {
+    fn parse_operation(input: &str) -> Res<'_, Operation> {
         alt((parse_add, parse_multiply))(input)
     }
 
-    fn parse_test_line(input: &str) -> Res<TestLine> {
+    fn parse_test_line(input: &str) -> Res<'_, TestLine> {
         map(preceded(tag("  Test: divisible by "), number), TestLine)(input)
     }
 
-    fn parse_true_line(input: &str) -> Res<TrueLine> {
+    fn parse_true_line(input: &str) -> Res<'_, TrueLine> {
         map(
             preceded(tag("    If true: throw to monkey "), number),
             TrueLine,
         )(input)
     }
 
-    fn parse_false_line(input: &str) -> Res<FalseLine> {
+    fn parse_false_line(input: &str) -> Res<'_, FalseLine> {
         map(
             preceded(tag("    If false: throw to monkey "), number),
             FalseLine,
         )(input)
     }
 
-    fn parse_monkey(input: &str) -> Res<Monkey> {
-        map(
-            tuple((
-                terminated(parse_monkey_line, line_ending),
-                terminated(parse_starting_items_line, line_ending),
-                terminated(parse_operation_line, line_ending),
-                terminated(parse_test_line, line_ending),
-                terminated(parse_true_line, line_ending),
-                terminated(parse_false_line, line_ending),
-            )),
-            |(
-                MonkeyLine(m),
-                StartingItemsLine(items),
-                OperationLine(lhs, operation, rhs),
-                TestLine(divisor),
-                TrueLine(throw_true),
-                FalseLine(throw_false),
-            )| Monkey {
-                idx: m,
-                operation: Expression {
-                    lhs,
-                    operation,
-                    rhs,
-                },
-                test: Test {
-                    divisor,
-                    throw_true,
-                    throw_false,
+    fn parse_monkey(input: &str) -> Res<'_, Monkey> {
+        context(
+            "monkey",
+            map(
+                tuple((
+                    terminated(parse_monkey_line, line_ending),
+                    terminated(parse_starting_items_line, line_ending),
+                    terminated(parse_operation_line, line_ending),
+                    terminated(parse_test_line, line_ending),
+                    terminated(parse_true_line, line_ending),
+                    parse_false_line,
+                )),
+                |(
+                    MonkeyLine(m),
+                    StartingItemsLine(items),
+                    OperationLine(lhs, operation, rhs),
+                    TestLine(divisor),
+                    TrueLine(throw_true),
+                    FalseLine(throw_false),
+                )| Monkey {
+                    idx: m,
+                    operation: Expression {
+                        lhs,
+                        operation,
+                        rhs,
+                    },
+                    test: Test {
+                        divisor,
+                        throw_true,
+                        throw_false,
+                    },
+                    items: items
+                        .iter()
+                        .map(|alert_level| Item {
+                            alert_level: *alert_level,
+                        })
+                        .collect_vec(),
                 },
-                items: items
-                    .iter()
-                    .map(|alert_level| Item {
-                        alert_level: *alert_level,
-                    })
-                    .collect_vec(),
-            },
+            ),
         )(input)
     }
 
-    fn parse_operation_line(input: &str) -> Res<OperationLine> {
+    fn parse_operation_line(input: &str) -> Res<'_, OperationLine> {
         map(
             preceded(
                 tag("  Operation: new = "),
@@ -294,106 +292,99 @@ mod parser {
         )(input)
     }
 
-    pub fn parse_monkeys(input: &str) -> Res<Vec<Monkey>> {
-        separated_list1(line_ending, parse_monkey)(input)
+    pub fn parse_monkeys(input: &str) -> Res<'_, Vec<Monkey>> {
+        blocks_of(parse_monkey)(input)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use nom::Finish;
-
     use super::*;
     #[test]
     fn test_parse_monkeys() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = advent_of_code::read_file("examples", 2022, 11);
         use Element::*;
         use Operation::*;
-        let expected = Ok((
-            "",
-            vec![
-                Monkey {
-                    idx: 0,
-                    items: vec![Item { alert_level: 79 }, Item { alert_level: 98 }],
-                    operation: Expression {
-                        lhs: Old,
-                        operation: Multiply,
-                        rhs: Literal(19),
-                    },
-                    test: Test {
-                        divisor: 23,
-                        throw_true: 2,
-                        throw_false: 3,
-                    },
+        let expected = Ok(vec![
+            Monkey {
+                idx: 0,
+                items: vec![Item { alert_level: 79 }, Item { alert_level: 98 }],
+                operation: Expression {
+                    lhs: Old,
+                    operation: Multiply,
+                    rhs: Literal(19),
                 },
-                Monkey {
-                    idx: 1,
-                    items: vec![
-                        Item { alert_level: 54 },
-                        Item { alert_level: 65 },
-                        Item { alert_level: 75 },
-                        Item { alert_level: 74 },
-                    ],
-                    operation: Expression {
-                        lhs: Old,
-                        operation: Add,
-                        rhs: Literal(6),
-                    },
-                    test: Test {
-                        divisor: 19,
-                        throw_true: 2,
-                        throw_false: 0,
-                    },
+                test: Test {
+                    divisor: 23,
+                    throw_true: 2,
+                    throw_false: 3,
                 },
-                Monkey {
-                    idx: 2,
-                    items: vec![
-                        Item { alert_level: 79 },
-                        Item { alert_level: 60 },
-                        Item { alert_level: 97 },
-                    ],
-                    operation: Expression {
-                        lhs: Old,
-                        operation: Multiply,
-                        rhs: Old,
-                    },
-                    test: Test {
-                        divisor: 13,
-                        throw_true: 1,
-                        throw_false: 3,
-                    },
+            },
+            Monkey {
+                idx: 1,
+                items: vec![
+                    Item { alert_level: 54 },
+                    Item { alert_level: 65 },
+                    Item { alert_level: 75 },
+                    Item { alert_level: 74 },
+                ],
+                operation: Expression {
+                    lhs: Old,
+                    operation: Add,
+                    rhs: Literal(6),
                 },
-                Monkey {
-                    idx: 3,
-                    items: vec![Item { alert_level: 74 }],
-                    operation: Expression {
-                        lhs: Old,
-                        operation: Add,
-                        rhs: Literal(3),
-                    },
-                    test: Test {
-                        divisor: 17,
-                        throw_true: 0,
-                        throw_false: 1,
-                    },
+                test: Test {
+                    divisor: 19,
+                    throw_true: 2,
+                    throw_false: 0,
+                },
+            },
+            Monkey {
+                idx: 2,
+                items: vec![
+                    Item { alert_level: 79 },
+                    Item { alert_level: 60 },
+                    Item { alert_level: 97 },
+                ],
+                operation: Expression {
+                    lhs: Old,
+                    operation: Multiply,
+                    rhs: Old,
+                },
+                test: Test {
+                    divisor: 13,
+                    throw_true: 1,
+                    throw_false: 3,
                 },
-            ],
-        ));
-        let res = parser::parse_monkeys(&input)
-            .finish()
-            .map_err(|e| e.to_string());
+            },
+            Monkey {
+                idx: 3,
+                items: vec![Item { alert_level: 74 }],
+                operation: Expression {
+                    lhs: Old,
+                    operation: Add,
+                    rhs: Literal(3),
+                },
+                test: Test {
+                    divisor: 17,
+                    throw_true: 0,
+                    throw_false: 1,
+                },
+            },
+        ]);
+        let res = parse_all(parser::parse_monkeys, &input);
 
         assert_eq!(res, expected);
     }
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = advent_of_code::read_file("examples", 2022, 11);
         assert_eq!(part_one(&input), Ok(10605));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = advent_of_code::read_file("examples", 2022, 11);
         assert_eq!(part_two(&input), Ok(2713310158));
     }
 }